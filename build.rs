@@ -0,0 +1,41 @@
+//! Generates the day registry from the `yYYYY_dayN` dependencies in our
+//! manifest, so wiring up a new day never needs to touch main.rs
+
+use std::fmt::Write;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let manifest = std::fs::read_to_string("Cargo.toml").expect("read Cargo.toml");
+
+    let mut days: Vec<(u32, u32)> = manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[dependencies]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| {
+            let (name, _) = line.split_once('=')?;
+
+            let (year, day) = name.trim().strip_prefix('y')?.split_once("_day")?;
+
+            Some((year.parse().ok()?, day.parse().ok()?))
+        })
+        .collect();
+
+    days.sort();
+
+    let mut code = String::from("pub static DAYS: &[aoc::Day] = &[\n");
+
+    for (year, day) in days {
+        writeln!(
+            code,
+            "    aoc::Day {{ year: {year}, number: {day}, solver: &y{year}_day{day}::Day{day} }},"
+        )
+        .unwrap();
+    }
+
+    code.push_str("];\n");
+
+    let out = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(format!("{out}/registry.rs"), code).expect("write registry");
+}
@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use aoc::{command, parse, Answer, Command, ParseError, Rng, Solution};
+
+/// Columns of numbers, read down the page. Usually two of them.
+#[derive(Clone)]
+pub struct Lines {
+    columns: Vec<Vec<i64>>,
+    /// How often each number shows up, per column
+    counts: Vec<HashMap<i64, i64>>,
+}
+
+/// How two of the columns stack up against each other
+#[derive(Debug, PartialEq, Eq)]
+pub struct Comparison {
+    /// Smallest to smallest, second smallest to second smallest and so on
+    pub distance: i64,
+    /// Every number on the left times how often it shows up on the right
+    pub similarity: i64,
+    /// How many different numbers the left has that the right doesn't
+    pub only_left: usize,
+    /// And the other way around
+    pub only_right: usize,
+}
+
+impl Lines {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut columns: Vec<Vec<i64>> = Vec::new();
+
+        for line in parse::lines(input) {
+            let row = line
+                .split_whitespace()
+                .map(|n| parse::number(input, n))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if columns.is_empty() {
+                if row.len() < 2 {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "expected at least two lists to compare",
+                    ));
+                }
+
+                columns = vec![Vec::new(); row.len()];
+            }
+
+            if row.len() != columns.len() {
+                let message = format!(
+                    "row has {} numbers, but the first one has {}",
+                    row.len(),
+                    columns.len()
+                );
+
+                return Err(ParseError::at(input, line, message));
+            }
+
+            for (column, n) in columns.iter_mut().zip(row) {
+                column.push(n);
+            }
+        }
+
+        if columns.is_empty() {
+            return Err(ParseError::eof(input, "expected some lists"));
+        }
+
+        let counts = columns
+            .iter()
+            .map(|column| {
+                let mut counts = HashMap::new();
+
+                for n in column {
+                    *counts.entry(*n).or_insert(0) += 1;
+                }
+
+                counts
+            })
+            .collect();
+
+        Ok(Self { columns, counts })
+    }
+
+    fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Pairs them up smallest first and adds up how far apart each pair is
+    fn distance(&self, left: usize, right: usize) -> i64 {
+        let mut left = self.columns[left].clone();
+        let mut right = self.columns[right].clone();
+
+        left.sort_unstable();
+        right.sort_unstable();
+
+        left.into_iter().zip(right).map(|(a, b)| (a - b).abs()).sum()
+    }
+
+    fn similarity(&self, left: usize, right: usize) -> i64 {
+        let right = &self.counts[right];
+
+        // Per number rather than per row, so repeats only get looked up once
+        self.counts[left]
+            .iter()
+            .map(|(n, count)| n * count * right.get(n).unwrap_or(&0))
+            .sum()
+    }
+
+    /// How many different numbers `left` has that `right` doesn't
+    fn only_in(&self, left: usize, right: usize) -> usize {
+        self.counts[left]
+            .keys()
+            .filter(|n| !self.counts[right].contains_key(n))
+            .count()
+    }
+
+    fn compare(&self, left: usize, right: usize) -> Comparison {
+        Comparison {
+            distance: self.distance(left, right),
+            similarity: self.similarity(left, right),
+            only_left: self.only_in(left, right),
+            only_right: self.only_in(right, left),
+        }
+    }
+}
+
+/// Two columns, `size` rows long, where the right one borrows about half its
+/// numbers from the left so there's something to be similar about. There's
+/// always at least one row, since empty lists don't parse.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let number = |rng: &mut Rng| rng.range(10000..100000);
+    let left: Vec<_> = (0..size.max(1)).map(|_| number(rng)).collect();
+    let mut lines = String::new();
+
+    for a in &left {
+        let b = if rng.chance(0.5) {
+            *rng.pick(&left)
+        } else {
+            number(rng)
+        };
+
+        lines.push_str(&format!("{a}   {b}\n"));
+    }
+
+    lines
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = Lines;
+
+    fn parse(input: &str) -> Result<Lines, ParseError> {
+        Lines::from_str(input)
+    }
+
+    fn part1(lines: &Lines) -> Answer {
+        lines.distance(0, 1).into()
+    }
+
+    fn part2(lines: &Lines) -> Answer {
+        lines.similarity(0, 1).into()
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![Command::new(
+            "compare",
+            "compare [LEFT RIGHT]: how columns LEFT (0) and RIGHT (1) stack up",
+            |lines, args| {
+                let left = command::arg_or(args, 0, "a column", 0)?;
+                let right = command::arg_or(args, 1, "a column", 1)?;
+                let columns = lines.column_count();
+
+                if left >= columns || right >= columns {
+                    return Err(format!("there are only {columns} columns"));
+                }
+
+                Ok(format!("{:?}", lines.compare(left, right)))
+            },
+        )]
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn example() {
+        let lines = Lines::from_str(EXAMPLE).unwrap();
+
+        assert_eq!(
+            lines.compare(0, 1),
+            Comparison {
+                distance: 11,
+                similarity: 31,
+                // 1 and 2 on the left, 5 and 9 on the right
+                only_left: 2,
+                only_right: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn more_columns() {
+        let lines = Lines::from_str("1 2 3\n4 5 6\n1 1 9\n").unwrap();
+
+        assert_eq!(lines.column_count(), 3);
+        // 1 to 3, 1 to 6 and 4 to 9
+        assert_eq!(lines.distance(0, 2), 2 + 5 + 5);
+        assert_eq!(lines.similarity(0, 1), 2);
+        assert_eq!(lines.similarity(1, 0), 2);
+        assert_eq!(lines.only_in(2, 0), 3);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let error = Lines::from_str("1   2\n3\n").err().unwrap();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "row has 1 numbers, but the first one has 2");
+
+        assert!(Lines::from_str("1   2\n3   4   5\n").is_err());
+        assert!(Lines::from_str("1\n2\n").is_err());
+        assert!(Lines::from_str("").is_err());
+    }
+
+    #[test]
+    fn generated_lists() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 500);
+            let lines = Lines::from_str(&input).unwrap();
+            // Rescanning the right for every left, like it used to
+            let slowly: i64 = lines.columns[0]
+                .iter()
+                .map(|a| a * lines.columns[1].iter().filter(|b| a == *b).count() as i64)
+                .sum();
+
+            assert_eq!(lines.similarity(0, 1), slowly, "seed {seed}");
+            assert!(slowly > 0);
+        }
+    }
+
+    #[test]
+    fn generated_nothing() {
+        let input = generate(&mut Rng::new(0), 0);
+        let lines = Lines::from_str(&input).unwrap();
+
+        assert_eq!(lines.columns[0].len(), 1);
+    }
+}
@@ -0,0 +1,298 @@
+use aoc::{Answer, ParseError, Rng, Solution};
+use grid::{Coord, Grid};
+use search::Search;
+
+// No bottoms allowed
+pub struct TopMap {
+    tiles: Grid<Tile>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Tile(u8);
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '0'..='9' => Some(Self(c.to_digit(10).unwrap() as u8)),
+            '.' => Some(Self::impassable()),
+            _ => None,
+        }
+    }
+
+    fn next(&self) -> Self {
+        Self(self.0 + 1)
+    }
+
+    fn is_end(&self) -> bool {
+        self.0 == 9
+    }
+
+    fn impassable() -> Self {
+        Self(42)
+    }
+}
+
+impl TopMap {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            tiles: Grid::parse(input, Tile::from_char)?,
+        })
+    }
+
+    fn starting_tiles(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.tiles
+            .iter()
+            .filter(|(_, tile)| **tile == Tile(0))
+            .map(|(coord, _)| coord)
+    }
+
+    fn adjacents_increasing(
+        &self,
+        start: Coord,
+        value: Tile,
+    ) -> impl Iterator<Item = (Tile, Coord)> + '_ {
+        self.tiles.neighbours(start).filter_map(move |next| {
+            let next_value = self.tiles[next];
+
+            if next_value == value.next() {
+                // eprintln!("{start:?} {value:?} {next:?} {next_value:?}");
+                Some((next_value, next))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Climbing a step at a time means every trail is as short as can be, so
+    /// the search's shortest paths are all the trails there are
+    fn score_for(&self, coord: Coord, repeats: bool, search: &mut Search<Coord, usize>) -> usize {
+        let up = |at: &Coord| self.adjacents_increasing(*at, self.tiles[*at]).map(|(_, next)| next);
+
+        search.bfs([coord], up, |_| false);
+
+        let ends = search
+            .visited()
+            .filter(|(at, _)| self.tiles[**at].is_end())
+            .map(|(at, _)| at);
+
+        if repeats {
+            ends.map(|end| search.count_paths(end) as usize).sum()
+        } else {
+            ends.count()
+        }
+    }
+
+    fn scores_increasing_total(&self, repeats: bool) -> usize {
+        let mut search = Search::new();
+
+        self.starting_tiles()
+            .map(|start| self.score_for(start, repeats, &mut search))
+            .sum()
+    }
+}
+
+/// A map `size` square of random heights and the odd impassable spot, with
+/// a trail from 0 to 9 wandering across it for every row
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let height = |rng: &mut Rng| char::from_digit(rng.below(10) as u32, 10).unwrap();
+    let cells = (0..size * size)
+        .map(|_| if rng.chance(0.05) { '.' } else { height(rng) })
+        .collect();
+    let mut map = Grid::new(size, size, cells);
+
+    for _ in 0..size {
+        let mut at = (rng.below(size), rng.below(size));
+        let mut trail = vec![];
+
+        for step in '0'..='9' {
+            map[at] = step;
+            trail.push(at);
+
+            let next: Vec<_> = map.neighbours(at).filter(|next| !trail.contains(next)).collect();
+
+            if next.is_empty() {
+                break;
+            }
+
+            at = *rng.pick(&next);
+        }
+    }
+
+    map.to_string()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = TopMap;
+
+    fn parse(input: &str) -> Result<TopMap, ParseError> {
+        TopMap::from_str(input)
+    }
+
+    fn part1(map: &TopMap) -> Answer {
+        map.scores_increasing_total(false).into()
+    }
+
+    fn part2(map: &TopMap) -> Answer {
+        map.scores_increasing_total(true).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The bigger example, checked in so these run on a fresh checkout too
+    const EXAMPLE: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn example_one() {
+        let map = TopMap::from_str(
+            "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+        )
+        .unwrap();
+
+        assert_eq!(map.score_for((2, 0), false, &mut Search::new()), 5);
+        assert_eq!(map.scores_increasing_total(false), 36)
+    }
+
+    #[test]
+    fn example_one_two() {
+        let map = TopMap::from_str(
+            "...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9",
+        )
+        .unwrap();
+
+        assert_eq!(map.scores_increasing_total(false), 2)
+    }
+
+    #[test]
+    fn example_one_four() {
+        let map = TopMap::from_str(
+            "..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....",
+        )
+        .unwrap();
+
+        assert_eq!(map.scores_increasing_total(false), 4)
+    }
+
+    #[test]
+    fn example_two() {
+        let map = TopMap::from_str(
+            "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+        )
+        .unwrap();
+
+        assert_eq!(map.scores_increasing_total(true), 81)
+    }
+
+    #[test]
+    fn starts() {
+        let map = TopMap::from_str(
+            "10..9..
+2...8..
+3...7..
+4567654
+...8..3
+...9..2
+.....01",
+        )
+        .unwrap();
+
+        assert_eq!(map.starting_tiles().count(), 2)
+    }
+
+    #[test]
+    fn coords() {
+        let map = TopMap::from_str(EXAMPLE).unwrap();
+
+        for i in 0..map.tiles.cells().len() {
+            let c = map.tiles.coord_of(i);
+
+            assert_eq!(map.tiles.cells()[i], map.tiles[c]);
+        }
+    }
+
+    #[test]
+    fn wider_than_tall() {
+        let map = TopMap::from_str(
+            "\
+0123456789
+..........",
+        )
+        .unwrap();
+
+        assert_eq!(map.starting_tiles().collect::<Vec<_>>(), [(0, 0)]);
+        assert_eq!(map.scores_increasing_total(false), 1);
+    }
+
+    /// Every trail from `at` up, the long way round
+    fn trails(map: &TopMap, at: Coord, ends: &mut Vec<Coord>) {
+        if map.tiles[at].is_end() {
+            ends.push(at);
+        }
+
+        for (_, next) in map.adjacents_increasing(at, map.tiles[at]) {
+            trails(map, next, ends);
+        }
+    }
+
+    #[test]
+    fn generated_maps() {
+        for seed in 0..20 {
+            let size = 1 + seed as usize * 2;
+            let input = generate(&mut Rng::new(seed), size);
+            let map = TopMap::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), size));
+            assert_eq!((map.tiles.width(), map.tiles.height()), (size, size));
+
+            let (mut scores, mut ratings) = (0, 0);
+
+            for start in map.starting_tiles() {
+                let mut ends = vec![];
+
+                trails(&map, start, &mut ends);
+                ratings += ends.len();
+                ends.sort();
+                ends.dedup();
+                scores += ends.len();
+            }
+
+            assert_eq!(map.scores_increasing_total(false), scores, "seed {seed}");
+            assert_eq!(map.scores_increasing_total(true), ratings, "seed {seed}");
+        }
+    }
+}
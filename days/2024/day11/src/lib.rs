@@ -0,0 +1,279 @@
+use std::{collections::HashMap, fmt::Write};
+
+use aoc::{command, parse, Answer, Command, ParseError, Rng, Solution, Variant};
+
+#[derive(Clone)]
+pub struct Stones {
+    stones: Vec<Stone>,
+}
+
+#[derive(Clone)]
+struct Stone(u64);
+
+impl Stones {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            stones: input
+                .split_ascii_whitespace()
+                .map(|n| parse::number(input, n).map(Stone))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn tick(&mut self, steps: usize) {
+        let mut stones = std::mem::take(&mut self.stones);
+        let mut next = Vec::with_capacity(stones.len());
+
+        let mut s = String::new();
+
+        for i in 0..steps {
+            log::debug!("step {i}, {} stones", stones.len());
+
+            for stone in stones.iter() {
+                stone.tick(&mut next, &mut s);
+            }
+
+            std::mem::swap(&mut next, &mut stones);
+
+            next.clear();
+        }
+
+        self.stones = stones;
+    }
+
+    fn tick_memo(&self, steps: u32) -> u64 {
+        let mut buf = String::new();
+        // Starts cold every time, so benchmarking this isn't a lie, and
+        // runs going at once don't trample each other's
+        let mut memo = HashMap::new();
+
+        self.stones
+            .iter()
+            .map(|stone| tick_memo(stone.0, steps, &mut memo, &mut buf))
+            .sum()
+    }
+
+    fn tick_nore(&self, steps: u32) -> u64 {
+        let stones: Vec<_> = self.stones.iter().map(|stone| stone.0).collect();
+
+        tick_memo_norecurse_goawaylemon(steps, &stones)
+    }
+}
+
+impl Stone {
+    fn tick(&self, stones: &mut Vec<Stone>, buf: &mut String) {
+        write!(buf, "{}", self.0).unwrap();
+
+        if buf.len().is_multiple_of(2) {
+            let left = buf[0..(buf.len() / 2)].parse().unwrap();
+            let right = buf[(buf.len() / 2)..buf.len()].parse().unwrap();
+
+            stones.push(Stone(left));
+            stones.push(Stone(right));
+        } else if self.0 == 0 {
+            stones.push(Stone(1));
+        } else {
+            stones.push(Stone(self.0 * 2024));
+        }
+
+        buf.clear();
+    }
+}
+
+fn tick_memo_norecurse_goawaylemon(steps: u32, stones: &[u64]) -> u64 {
+    let mut buf = String::new();
+    let mut cache = HashMap::new();
+    let mut stack = Vec::new();
+    let mut total = 0;
+
+    for stone in stones {
+        stack.push((*stone, steps, 0, 0));
+
+        while let Some((stone, steps, current, backref)) = stack.pop() {
+            let stone_index = stack.len();
+            let done = if steps == 0 {
+                Some(1)
+            } else if let Some(&sum) = cache.get(&(stone, steps)) {
+                Some(sum)
+            } else if current != 0 {
+                cache.insert((stone, steps), current);
+
+                Some(current)
+            } else {
+                None
+            };
+
+            // Stones from the input (the same one twice, say) can be done
+            // already too, and there's nothing under them to add to
+            if let Some(count) = done {
+                if stone_index == 0 {
+                    total += count;
+                } else {
+                    stack[backref].2 += count;
+                }
+
+                continue;
+            }
+
+            // unpop
+            stack.push((stone, steps, current, backref));
+
+            write!(buf, "{}", stone).unwrap();
+
+            if buf.len().is_multiple_of(2) {
+                let left = buf[0..(buf.len() / 2)].parse().unwrap();
+                let right = buf[(buf.len() / 2)..buf.len()].parse().unwrap();
+
+                buf.clear();
+
+                stack.push((right, steps - 1, 0, stone_index));
+                stack.push((left, steps - 1, 0, stone_index));
+            } else if stone == 0 {
+                buf.clear();
+
+                stack.push((1, steps - 1, 0, stone_index));
+            } else {
+                buf.clear();
+
+                stack.push((stone * 2024, steps - 1, 0, stone_index));
+            }
+        }
+    }
+
+    total
+}
+
+fn tick_memo(stone: u64, steps: u32, memo: &mut HashMap<(u64, u32), u64>, buf: &mut String) -> u64 {
+    if steps == 0 {
+        return 1;
+    }
+
+    if let Some(&count) = memo.get(&(stone, steps)) {
+        return count;
+    }
+
+    write!(buf, "{}", stone).unwrap();
+
+    let count = if buf.len().is_multiple_of(2) {
+        let left = buf[0..(buf.len() / 2)].parse().unwrap();
+        let right = buf[(buf.len() / 2)..buf.len()].parse().unwrap();
+
+        buf.clear();
+
+        tick_memo(left, steps - 1, memo, buf) + tick_memo(right, steps - 1, memo, buf)
+    } else if stone == 0 {
+        buf.clear();
+
+        tick_memo(1, steps - 1, memo, buf)
+    } else {
+        buf.clear();
+
+        tick_memo(stone * 2024, steps - 1, memo, buf)
+    };
+
+    memo.insert((stone, steps), count);
+
+    count
+}
+
+/// A line of `size` stones, up to seven digits each like the real ones
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<_> = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.range(1..8) as u32;
+
+            rng.below(10usize.pow(digits)).to_string()
+        })
+        .collect();
+
+    stones.join(" ") + "\n"
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Stones;
+
+    fn parse(input: &str) -> Result<Stones, ParseError> {
+        Stones::from_str(input)
+    }
+
+    fn part1(stones: &Stones) -> Answer {
+        let mut stones = stones.clone();
+
+        stones.tick(25);
+
+        stones.stones.len().into()
+    }
+
+    fn part2(stones: &Stones) -> Answer {
+        stones.tick_nore(75).into()
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant::new(1, "memo", |stones| stones.tick_memo(25).into()),
+            Variant::new(1, "nore", |stones| stones.tick_nore(25).into()),
+            Variant::new(2, "memo", |stones| stones.tick_memo(75).into()),
+        ]
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![Command::new(
+            "blink",
+            "blink N: how many stones after N blinks",
+            |stones, args| {
+                Ok(stones.tick_nore(command::arg(args, 0, "a blink count")?).to_string())
+            },
+        )]
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memo_runs_side_by_side() {
+        let inputs = ["125 17", "0 1 10 99 999", "2024 7 6", "7 7 2024"];
+
+        std::thread::scope(|scope| {
+            for input in inputs {
+                scope.spawn(move || {
+                    let stones = Stones::from_str(input).unwrap();
+
+                    for _ in 0..20 {
+                        assert_eq!(stones.tick_memo(40), stones.tick_nore(40), "{input}");
+                    }
+                });
+            }
+        });
+
+        assert_eq!(Stones::from_str("125 17").unwrap().tick_memo(25), 55312);
+    }
+
+    #[test]
+    fn generated_stones() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 5);
+            let stones = Stones::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 5));
+            assert_eq!(stones.stones.len(), 5);
+
+            let mut ticked = stones.clone();
+
+            ticked.tick(25);
+
+            let count = ticked.stones.len() as u64;
+
+            assert_eq!(stones.tick_memo(25), count, "seed {seed}");
+            assert_eq!(stones.tick_nore(25), count, "seed {seed}");
+            assert_eq!(stones.tick_memo(75), stones.tick_nore(75), "seed {seed}");
+        }
+    }
+}
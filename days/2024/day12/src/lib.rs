@@ -2,12 +2,13 @@
 
 use std::collections::HashSet;
 
-const INPUT: &str = include_str!("../../../inputs/day12.txt");
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+use search::Search;
 
 type Coord = (usize, usize);
 
 #[derive(Debug)]
-struct Farm {
+pub struct Farm {
     tiles: Vec<Vec<Tile>>,
     regions: Vec<Region>,
 }
@@ -69,20 +70,17 @@ impl Direction {
 }
 
 impl Tile {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            'A'..='Z' => Tile(c),
-            _ => unreachable!(),
+            'A'..='Z' => Some(Tile(c)),
+            _ => None,
         }
     }
 }
 
 impl Farm {
-    fn from_str(input: &str) -> Self {
-        let mut tiles: Vec<Vec<_>> = input
-            .lines()
-            .map(|line| line.chars().map(|c| (Tile::from_char(c), false)).collect())
-            .collect();
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut tiles = parse::grid(input, |c| Tile::from_char(c).map(|tile| (tile, false)))?;
 
         let regions = Self::carve_regions(&mut tiles);
 
@@ -98,11 +96,12 @@ impl Farm {
             })
             .collect();
 
-        Self { tiles, regions }
+        Ok(Self { tiles, regions })
     }
 
     fn carve_regions(tiles: &mut [Vec<(Tile, bool)>]) -> Vec<Region> {
         let mut regions = Vec::new();
+        let mut search = Search::new();
 
         for y in 0..tiles.len() {
             for x in 0..tiles[0].len() {
@@ -110,7 +109,7 @@ impl Farm {
                     continue;
                 }
 
-                regions.push(Region::carve_out(tiles, (x, y)));
+                regions.push(Region::carve_out(tiles, (x, y), &mut search));
             }
         }
 
@@ -127,7 +126,11 @@ impl Farm {
 }
 
 impl Region {
-    fn carve_out(tiles: &mut [Vec<(Tile, bool)>], (x, y): Coord) -> Self {
+    fn carve_out(
+        tiles: &mut [Vec<(Tile, bool)>],
+        (x, y): Coord,
+        search: &mut Search<Coord, usize>,
+    ) -> Self {
         let width = tiles[0].len() as isize;
         let height = tiles.len() as isize;
 
@@ -146,30 +149,24 @@ impl Region {
         };
 
         assert!(!tiles[y][x].1);
-        tiles[y][x].1 = true;
 
         let tile = tiles[y][x].0.clone();
+        let same = |&(x, y): &Coord| {
+            adjacents(x, y).filter(|&(next_x, next_y)| tiles[next_y][next_x].0 == tile)
+        };
 
-        let mut coords = vec![(x, y)];
-        let mut processing: Vec<_> = adjacents(x, y).collect();
+        search.bfs([(x, y)], same, |_| false);
 
-        while let Some((x, y)) = processing.pop() {
-            let (next_tile, walked) = &mut tiles[y][x];
+        let coords: Vec<_> = search.visited().map(|(coord, _)| *coord).collect();
 
-            if *next_tile != tile || *walked {
-                continue;
-            }
-            *walked = true;
-
-            coords.push((x, y));
-
-            processing.extend(adjacents(x, y));
+        for &(x, y) in &coords {
+            tiles[y][x].1 = true;
         }
 
         let area = coords.len() as u64;
         let (perimeter, sides) = Self::calc_perimeter(&coords, tiles);
 
-        // eprintln!("final sides {sides} for {tile:?}");
+        log::debug!("final sides {sides} for {tile:?}");
 
         Self {
             tile,
@@ -241,7 +238,7 @@ impl Region {
 
         edges.sort();
 
-        // dbg!(&edges);
+        log::trace!("edges: {edges:?}");
 
         let mut turns = 0;
 
@@ -285,7 +282,7 @@ impl Region {
             let mut current = edge.clone();
             edged.insert(current.clone());
 
-            // eprintln!("starting from {current:?}");
+            log::trace!("starting from {current:?}");
 
             turns += 1;
 
@@ -294,14 +291,12 @@ impl Region {
                 let next_right = right(&current, &direction);
                 let next_straight = forward_lmao(&current, &direction);
 
-                // dbg!(&next_left);
-                // dbg!(&next_right);
-                // dbg!(&next_straight);
+                log::trace!("left {next_left:?}, right {next_right:?}, straight {next_straight:?}");
 
                 if let Some(left) = next_left {
                     if edgeset.contains(&left) && !edged.contains(&left) {
                         direction = direction.left();
-                        // eprintln!("turning left {left:?}, going {direction:?}");
+                        log::trace!("turning left {left:?}, going {direction:?}");
                         current = left;
                         edged.insert(current.clone());
 
@@ -320,7 +315,7 @@ impl Region {
                 if let Some(right) = next_right {
                     if edgeset.contains(&right) && !edged.contains(&right) {
                         direction = direction.right();
-                        // eprintln!("turning right {right:?}, going {direction:?}");
+                        log::trace!("turning right {right:?}, going {direction:?}");
                         current = right;
                         edged.insert(current.clone());
 
@@ -343,7 +338,7 @@ impl Region {
                     }
 
                     if edgeset.contains(&forward) {
-                        // eprintln!("forward {forward:?}");
+                        log::trace!("forward {forward:?}");
                         current = forward;
                         edged.insert(current.clone());
 
@@ -351,13 +346,8 @@ impl Region {
                     }
                 }
 
-                // dbg!(left(&current, &direction));
-                // dbg!(right(&current, &direction));
-                // dbg!(forward_lmao(&current, &direction));
-                // dbg!(current, direction);
-
                 // unreachable!("should never get here");
-                // eprintln!("sides so far: {turns}");
+                log::trace!("stuck at {current:?} going {direction:?}, sides so far: {turns}");
                 break;
             }
         }
@@ -405,16 +395,53 @@ impl Region {
     }
 }
 
-pub fn part1() {
-    let farm = Farm::from_str(INPUT);
+/// A farm `size` plots square, sown at random and then left to spread into
+/// each other for a while so the regions come out blobby
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let plants: Vec<char> = ('A'..='Z').take(size / 2 + 1).collect();
+    let mut plots: Vec<Vec<char>> = (0..size)
+        .map(|_| (0..size).map(|_| *rng.pick(&plants)).collect())
+        .collect();
+
+    for _ in 0..size * size * 4 {
+        let (x, y) = (rng.below(size), rng.below(size));
+        let (from_x, from_y) = match rng.below(4) {
+            0 => (x.saturating_sub(1), y),
+            1 => ((x + 1).min(size - 1), y),
+            2 => (x, y.saturating_sub(1)),
+            _ => (x, (y + 1).min(size - 1)),
+        };
+
+        plots[y][x] = plots[from_y][from_x];
+    }
 
-    dbg!(farm.price());
+    plots
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
 }
 
-pub fn part2() {
-    let farm = Farm::from_str(INPUT);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Farm;
 
-    dbg!(farm.price_straight());
+    fn parse(input: &str) -> Result<Farm, ParseError> {
+        Farm::from_str(input)
+    }
+
+    fn part1(farm: &Farm) -> Answer {
+        farm.price().into()
+    }
+
+    fn part2(farm: &Farm) -> Answer {
+        farm.price_straight().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -428,30 +455,31 @@ mod tests {
 BBCD
 BBCC
 EEEC",
-        );
+        )
+        .unwrap();
 
         assert_eq!(farm.regions.len(), 5);
 
         assert_eq!(farm.price(), 140);
         assert_eq!(farm.price_straight(), 80);
 
-        assert_eq!(farm.regions[0].tile, Tile::from_char('A'));
+        assert_eq!(farm.regions[0].tile, Tile::from_char('A').unwrap());
         assert_eq!(farm.regions[0].area, 4);
         assert_eq!(farm.regions[0].perimeter, 10);
 
-        assert_eq!(farm.regions[1].tile, Tile::from_char('B'));
+        assert_eq!(farm.regions[1].tile, Tile::from_char('B').unwrap());
         assert_eq!(farm.regions[1].area, 4);
         assert_eq!(farm.regions[1].perimeter, 8);
 
-        assert_eq!(farm.regions[2].tile, Tile::from_char('C'));
+        assert_eq!(farm.regions[2].tile, Tile::from_char('C').unwrap());
         assert_eq!(farm.regions[2].area, 4);
         assert_eq!(farm.regions[2].perimeter, 10);
 
-        assert_eq!(farm.regions[3].tile, Tile::from_char('D'));
+        assert_eq!(farm.regions[3].tile, Tile::from_char('D').unwrap());
         assert_eq!(farm.regions[3].area, 1);
         assert_eq!(farm.regions[3].perimeter, 4);
 
-        assert_eq!(farm.regions[4].tile, Tile::from_char('E'));
+        assert_eq!(farm.regions[4].tile, Tile::from_char('E').unwrap());
         assert_eq!(farm.regions[4].area, 3);
         assert_eq!(farm.regions[4].perimeter, 8);
     }
@@ -469,7 +497,8 @@ VVIIICJJEE
 MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE",
-        );
+        )
+        .unwrap();
 
         assert_eq!(farm.price(), 1930);
         assert_eq!(farm.price_straight(), 1206);
@@ -484,7 +513,8 @@ AAABBA
 ABBAAA
 ABBAAA
 AAAAAA",
-        );
+        )
+        .unwrap();
 
         assert_eq!(farm.price_straight(), 368);
     }
@@ -497,8 +527,58 @@ EXXXX
 EEEEE
 EXXXX
 EEEEE",
-        );
+        )
+        .unwrap();
 
         assert_eq!(farm.price_straight(), 236);
     }
+
+    #[test]
+    fn generated_farms() {
+        for seed in 0..20 {
+            let size = 1 + seed as usize;
+            let input = generate(&mut Rng::new(seed), size);
+            let farm = Farm::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), size));
+            assert_eq!((farm.tiles[0].len(), farm.tiles.len()), (size, size));
+
+            // Fences counted plot by plot, and sides counted by their corners
+            let plant = |x: isize, y: isize| {
+                let row = usize::try_from(y).ok().and_then(|y| farm.tiles.get(y));
+
+                row.and_then(|row| row.get(usize::try_from(x).ok()?))
+            };
+            let (mut price, mut price_straight) = (0, 0);
+
+            for region in &farm.regions {
+                let (mut perimeter, mut corners) = (0, 0);
+
+                for &(x, y) in &region.coords {
+                    let (x, y) = (x as isize, y as isize);
+                    let same = |dx, dy| plant(x + dx, y + dy) == Some(&region.tile);
+                    let sides = [(0, -1), (1, 0), (0, 1), (-1, 0), (0, -1)];
+
+                    perimeter += sides[..4].iter().filter(|&&(dx, dy)| !same(dx, dy)).count();
+                    corners += sides
+                        .windows(2)
+                        .filter(|pair| {
+                            let [(ax, ay), (bx, by)] = [pair[0], pair[1]];
+                            let (a, b) = (same(ax, ay), same(bx, by));
+
+                            (!a && !b) || (a && b && !same(ax + bx, ay + by))
+                        })
+                        .count();
+                }
+
+                let area = region.coords.len() as u64;
+
+                price += area * perimeter as u64;
+                price_straight += area * corners as u64;
+            }
+
+            assert_eq!(farm.price(), price, "seed {seed}");
+            assert_eq!(farm.price_straight(), price_straight, "seed {seed}");
+        }
+    }
 }
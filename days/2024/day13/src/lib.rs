@@ -0,0 +1,275 @@
+use aoc::{parse, Answer, ParseError, Rng, Solution, Variant};
+
+#[derive(Debug)]
+pub struct Arcadia {
+    machines: Vec<Machine>,
+}
+
+#[derive(Debug)]
+struct Machine {
+    ax: i64,
+    ay: i64,
+    bx: i64,
+    by: i64,
+    px: i64, // hi
+    py: i64,
+}
+
+impl Arcadia {
+    const ONE_GAZILLION: i64 = 10000000000000;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let lines = [
+            ("Button A: X+", ", Y+"),
+            ("Button B: X+", ", Y+"),
+            ("Prize: X=", ", Y="),
+        ];
+        let mut machines = Vec::new();
+
+        for block in scan::blocks(input) {
+            let mut block_lines = parse::lines(block);
+            let mut numbers = [0; 6];
+
+            for (i, (x, y)) in lines.iter().enumerate() {
+                let line = block_lines.next().ok_or_else(|| {
+                    ParseError::at(input, &block[block.len()..], format!("expected a {x} line"))
+                })?;
+
+                (numbers[i * 2], numbers[i * 2 + 1]) = scan::line(input, line, |s| {
+                    s.literal(x)?;
+                    let x = s.unsigned()?;
+                    s.literal(y)?;
+
+                    Ok((x, s.unsigned()?))
+                })?;
+            }
+
+            let [ax, ay, bx, by, px, py] = numbers;
+
+            machines.push(Machine {
+                ax,
+                ay,
+                bx,
+                by,
+                px,
+                py,
+            });
+
+            if let Some(line) = block_lines.next() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "expected a blank line between machines",
+                ));
+            }
+        }
+
+        Ok(Self { machines })
+    }
+
+    fn sum_tokens(&self) -> u64 {
+        self.sum_tokens_by(0, Machine::solve_g)
+    }
+
+    fn sum_tokens_with_bullshit_offset(&self) -> u64 {
+        self.sum_tokens_by(Self::ONE_GAZILLION, Machine::solve_g)
+    }
+
+    fn sum_tokens_by(&self, offset: i64, solver: fn(&Machine, i64) -> Option<(u64, u64)>) -> u64 {
+        self.machines
+            .iter()
+            .filter_map(|machine| solver(machine, offset))
+            .fold(0, |sum, (a, b)| sum + a * 3 + b)
+    }
+}
+
+impl Machine {
+    fn solve(&self, offset: i64) -> Option<(u64, u64)> {
+        let Self {
+            ax,
+            ay,
+            bx,
+            by,
+            mut px,
+            mut py,
+        } = self;
+        (py, px) = (py + offset, px + offset);
+
+        let deterrance = (ax * by - bx * ay) as f64;
+        let determination = (ax * py - ay * px) as f64;
+        let detachment = (px * by - bx * py) as f64;
+
+        let a = detachment / deterrance;
+        let b = determination / deterrance;
+
+        if a.fract() != 0.0 || b.fract() != 0.0 {
+            None
+        } else {
+            Some((a as u64, b as u64))
+        }
+    }
+
+    fn solve_g(&self, offset: i64) -> Option<(u64, u64)> {
+        let Self {
+            ax,
+            ay,
+            bx,
+            by,
+            mut px,
+            mut py,
+        } = self;
+        (py, px) = (py + offset, px + offset);
+
+        // first column is eliminated by construction
+        let r2 = (0, ax * by - bx * ay, ax * py - ay * px);
+
+        let b = (r2.2 as f64) / (r2.1 as f64);
+        if b.fract() != 0.0 {
+            return None;
+        }
+
+        let a = (px as f64 - *bx as f64 * b) / (*ax as f64);
+        if a.fract() != 0.0 {
+            return None;
+        }
+
+        Some((a as u64, b as u64))
+    }
+}
+
+/// `size` claw machines whose buttons never push the same way. A third of
+/// them have the prize a whole number of presses away, a third are a touch
+/// off, and the rest only work out once the prize is a gazillion further
+/// along. Comes with what parts 1 and 2 should add up to.
+fn generate_machines(rng: &mut Rng, size: usize) -> (String, [u64; 2]) {
+    let far = Arcadia::ONE_GAZILLION;
+    let mut machines = vec![];
+    let mut tokens = [0, 0];
+
+    while machines.len() < size.max(1) {
+        let [ax, ay, bx, by] = [(); 4].map(|_| rng.range(10..100) as i64);
+        let det = ax * by - bx * ay;
+
+        if det == 0 {
+            continue;
+        }
+
+        // Whether some number of presses, maybe a negative one, lands on it
+        let whole =
+            |px: i64, py: i64| (px * by - bx * py) % det == 0 && (ax * py - ay * px) % det == 0;
+        let kind = rng.below(3);
+        let (mut a, mut b) = (rng.range(1..101) as i64, rng.range(1..101) as i64);
+        let (shift_x, shift_y) = match kind {
+            0 => (0, 0),
+            1 => (1, 0),
+            _ => {
+                // Heading for a gazillion each way, with a few more presses
+                // on top so the prize ends up on the right side of zero
+                a += (far * by - bx * far) / det;
+                b += (ax * far - ay * far) / det;
+
+                (-far, -far)
+            }
+        };
+        let (px, py) = (a * ax + b * bx + shift_x, a * ay + b * by + shift_y);
+
+        // Each one should only work out for the part it's meant for
+        let (near, gazillion) = (whole(px, py), whole(px + far, py + far));
+
+        if a < 0 || b < 0 || px < 0 || py < 0 || near != (kind == 0) || gazillion != (kind == 2) {
+            continue;
+        }
+
+        match kind {
+            0 => tokens[0] += (a * 3 + b) as u64,
+            2 => tokens[1] += (a * 3 + b) as u64,
+            _ => {}
+        }
+
+        machines.push(format!(
+            "Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}\n"
+        ));
+    }
+
+    (machines.join("\n"), tokens)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Arcadia;
+
+    fn parse(input: &str) -> Result<Arcadia, ParseError> {
+        Arcadia::from_str(input)
+    }
+
+    fn part1(arcade: &Arcadia) -> Answer {
+        arcade.sum_tokens().into()
+    }
+
+    fn part2(arcade: &Arcadia) -> Answer {
+        arcade.sum_tokens_with_bullshit_offset().into()
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant::new(1, "cramer", |arcade| {
+                arcade.sum_tokens_by(0, Machine::solve).into()
+            }),
+            Variant::new(2, "cramer", |arcade| {
+                arcade.sum_tokens_by(Arcadia::ONE_GAZILLION, Machine::solve).into()
+            }),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate_machines(rng, size).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let machine = Machine {
+            ax: 94,
+            ay: 34,
+            bx: 22,
+            by: 67,
+            px: 8400,
+            py: 5400,
+        };
+
+        assert_eq!(machine.solve(0), Some((80, 40)));
+        assert_eq!(machine.solve(0), machine.solve_g(0));
+    }
+
+    #[test]
+    fn generated_machines() {
+        for seed in 0..20 {
+            let (input, [part1, part2]) = generate_machines(&mut Rng::new(seed), 50);
+            let arcade = Arcadia::from_str(&input).unwrap();
+
+            assert_eq!(input, generate_machines(&mut Rng::new(seed), 50).0);
+            assert_eq!(arcade.machines.len(), 50);
+            assert_eq!(arcade.sum_tokens(), part1, "seed {seed}");
+            assert_eq!(
+                arcade.sum_tokens_by(0, Machine::solve),
+                part1,
+                "seed {seed}"
+            );
+            assert_eq!(
+                arcade.sum_tokens_with_bullshit_offset(),
+                part2,
+                "seed {seed}"
+            );
+            assert_eq!(
+                arcade.sum_tokens_by(Arcadia::ONE_GAZILLION, Machine::solve),
+                part2,
+                "seed {seed}"
+            );
+        }
+    }
+}
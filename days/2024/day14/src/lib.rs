@@ -0,0 +1,343 @@
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+use scan::Scanner;
+
+type Offset = (usize, usize);
+type Velocity = (isize, isize);
+
+#[derive(Debug, Clone)]
+pub struct Bathroom {
+    width: usize,
+    height: usize,
+    robots: Vec<BunBot>,
+}
+
+#[derive(Debug, Clone)]
+struct BunBot {
+    position: Offset,
+    velocity: Velocity,
+}
+
+/// Scans a position along a wall `size` long, complaining right at it if the
+/// robot would start off the end
+fn position(input: &str, s: &mut Scanner, size: usize) -> Result<usize, ParseError> {
+    let before = s.rest();
+    let n = s.unsigned()?;
+
+    if n >= size {
+        let digits = &before[..before.len() - s.rest().len()];
+
+        return Err(ParseError::at(
+            input,
+            digits,
+            format!("that robot starts outside the bathroom, it's only {size} across"),
+        ));
+    }
+
+    Ok(n)
+}
+
+impl BunBot {
+    fn from_line(input: &str, line: &str, width: usize, height: usize) -> Result<Self, ParseError> {
+        scan::line(input, line, |s| {
+            s.literal("p=")?;
+            let x = position(input, s, width)?;
+            s.literal(",")?;
+            let y = position(input, s, height)?;
+            s.literal(" v=")?;
+            let dx = s.signed()?;
+            s.literal(",")?;
+            let dy = s.signed()?;
+            // /tease
+
+            Ok(BunBot {
+                position: (x, y),
+                velocity: (dx, dy),
+            })
+        })
+    }
+
+    fn tick(&mut self, width: usize, height: usize) {
+        let (x, y) = self.position;
+
+        // Robots can be quick enough to wrap around more than once a tick
+        self.position = (
+            (x as isize + self.velocity.0).rem_euclid(width as isize) as usize,
+            (y as isize + self.velocity.1).rem_euclid(height as isize) as usize,
+        );
+    }
+}
+
+impl Bathroom {
+    fn from_str(input: &str, width: usize, height: usize) -> Result<Self, ParseError> {
+        Ok(Self {
+            width,
+            height,
+            robots: parse::lines(input)
+                .map(|line| BunBot::from_line(input, line, width, height))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn tick(&mut self) {
+        self.robots
+            .iter_mut()
+            .for_each(|bot| bot.tick(self.width, self.height));
+    }
+
+    fn count_quads(&self) -> u64 {
+        let mut cleft = 0;
+        let mut cright = 0;
+        let mut dreft = 0;
+        let mut dright = 0;
+
+        let mw = self.width / 2;
+        let mh = self.height / 2;
+
+        for bot in self.robots.iter() {
+            if bot.position.0 < mw && bot.position.1 < mh {
+                cleft += 1;
+            } else if bot.position.0 > mw && bot.position.1 < mh {
+                cright += 1;
+            } else if bot.position.0 < mw && bot.position.1 > mh {
+                dreft += 1;
+            } else if bot.position.0 > mw && bot.position.1 > mh {
+                dright += 1;
+            }
+        }
+
+        cleft * cright * dreft * dright
+    }
+}
+
+impl Bathroom {
+    /// How many robots are on every spot, row by row
+    fn counts(&self) -> Vec<u32> {
+        let mut counts = vec![0; self.width * self.height];
+
+        for robot in self.robots.iter() {
+            let (x, y) = robot.position;
+
+            counts[y * self.width + x] += 1;
+        }
+
+        counts
+    }
+
+    /// Draws the robots
+    fn render(&self) -> String {
+        let counts = self.counts();
+        let mut s = String::with_capacity((self.width + 1) * self.height);
+
+        for row in counts.chunks(self.width) {
+            for &n in row {
+                s.push(match n {
+                    0 => ' ',
+                    n => n.to_string().chars().next().unwrap(),
+                });
+            }
+
+            s.push('\n');
+        }
+
+        s
+    }
+
+    /// The entropy: the longest run of robots side by side, which gets big
+    /// when they line up into a picture
+    fn entropy(&self) -> usize {
+        let mut longest = 0;
+
+        for row in self.counts().chunks(self.width) {
+            let mut run = 0;
+
+            for &n in row {
+                run = if n > 0 { run + 1 } else { 0 };
+                longest = longest.max(run);
+            }
+        }
+
+        longest
+    }
+}
+
+impl std::fmt::Display for Bathroom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// `size` robots in a `width` by `height` bathroom, a third of them (or as
+/// many as fit) lining up in a row at some secret step and the rest all over
+/// the place. Comes with the step, which part 2 should find so long as
+/// there are enough robots for the row to stand out.
+fn generate_robots(rng: &mut Rng, size: usize, width: usize, height: usize) -> (String, usize) {
+    let size = size.max(1);
+    let step = rng.below(width * height);
+    let row = (size / 3).clamp(1, width / 2);
+    let (row_x, row_y) = (rng.below(width - row + 1), rng.below(height));
+    let mut lines = String::new();
+
+    for i in 0..size {
+        let (x, y) = if i < row {
+            (row_x + i, row_y)
+        } else {
+            (rng.below(width), rng.below(height))
+        };
+        let dx = rng.below(width * 2 - 1) as isize - (width as isize - 1);
+        let dy = rng.below(height * 2 - 1) as isize - (height as isize - 1);
+        let back = step as isize;
+        let x = (x as isize - dx * back).rem_euclid(width as isize);
+        let y = (y as isize - dy * back).rem_euclid(height as isize);
+
+        lines.push_str(&format!("p={x},{y} v={dx},{dy}\n"));
+    }
+
+    (lines, step)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Bathroom;
+
+    fn parse(input: &str) -> Result<Bathroom, ParseError> {
+        Bathroom::from_str(input, 101, 103)
+    }
+
+    fn part1(bathroom: &Bathroom) -> Answer {
+        let mut bathroom = bathroom.clone();
+
+        for _ in 0..100 {
+            bathroom.tick();
+        }
+
+        bathroom.count_quads().into()
+    }
+
+    fn part2(bathroom: &Bathroom) -> Answer {
+        let mut bathroom = bathroom.clone();
+        let mut best = (0, 0);
+
+        // Robots are back where they started after width * height ticks,
+        // so whichever frame lines up the most robots before then is the
+        // one with the tree, if there's a tree at all
+        for step in 0..bathroom.width * bathroom.height {
+            aoc::check_cancelled();
+
+            let entropy = bathroom.entropy();
+
+            if entropy > best.0 {
+                best = (entropy, step);
+            }
+
+            bathroom.tick();
+        }
+
+        best.1.into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate_robots(rng, size, 101, 103).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let mut bathroom = Bathroom::from_str(
+            "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3",
+            11,
+            7,
+        )
+        .unwrap();
+
+        for _ in 0..100 {
+            bathroom.tick();
+        }
+
+        assert_eq!(bathroom.count_quads(), 12);
+    }
+
+    #[test]
+    fn lines_up_once() {
+        // Six robots that only end up side by side after 30 ticks, which is
+        // no tree but still the best lined up they get
+        let input: Vec<_> = (0..6)
+            .map(|i: isize| {
+                let (dx, dy) = (i + 1, 2 * i - 3);
+                let x = (i - 30 * dx).rem_euclid(11);
+                let y = (3 - 30 * dy).rem_euclid(7);
+
+                format!("p={x},{y} v={dx},{dy}")
+            })
+            .collect();
+        let bathroom = Bathroom::from_str(&input.join("\n"), 11, 7).unwrap();
+
+        assert_eq!(Day14::part2(&bathroom), 30.into());
+    }
+
+    #[test]
+    fn generated_robots() {
+        // A smaller bathroom than the real one, so part 2 doesn't take all day
+        for seed in 0..10 {
+            let (input, step) = generate_robots(&mut Rng::new(seed), 150, 31, 29);
+            let mut bathroom = Bathroom::from_str(&input, 31, 29).unwrap();
+
+            assert_eq!(input, generate_robots(&mut Rng::new(seed), 150, 31, 29).0);
+            assert_eq!(bathroom.robots.len(), 150);
+            assert_eq!(Day14::part2(&bathroom), step.into(), "seed {seed}");
+
+            for _ in 0..step {
+                bathroom.tick();
+            }
+
+            // Stragglers can land on either end of the row and make it longer
+            assert!(bathroom.entropy() >= 15, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn fast_robots() {
+        let mut bathroom = Bathroom::from_str("p=1,0 v=-300,1\np=10,6 v=23,-15\n", 11, 7).unwrap();
+
+        bathroom.tick();
+
+        // -299 and 33 across wrap around to 9 and 0, and -9 down to 5
+        assert_eq!(bathroom.robots[0].position, (9, 1));
+        assert_eq!(bathroom.robots[1].position, (0, 5));
+    }
+
+    #[test]
+    fn robots_outside() {
+        let error = Bathroom::from_str("p=1,0 v=1,1\np=150,0 v=-3,1\n", 101, 103)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.message,
+            "that robot starts outside the bathroom, it's only 101 across"
+        );
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 3, "150")
+        );
+
+        let error = Bathroom::from_str("p=0,7 v=1,1", 11, 7).err().unwrap();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (1, 5, "7"));
+    }
+}
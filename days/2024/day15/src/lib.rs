@@ -0,0 +1,563 @@
+use std::fmt::Display;
+
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+use grid::{Coord, Direction, Grid};
+
+#[derive(Debug, Clone)]
+struct Sokoban {
+    tiles: Grid<Tile>,
+}
+
+/// Both warehouses share the move list, only the map gets fatter
+#[derive(Debug)]
+pub struct Warehouse {
+    sokoban: Sokoban,
+    fat_sokoban: Sokoban,
+    moveset: Moveset,
+}
+
+#[derive(Debug)]
+struct Moveset {
+    moves: Vec<Direction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tile {
+    Free,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+    Bot,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Free),
+            '#' => Some(Self::Wall),
+            'O' => Some(Self::Box),
+            '@' => Some(Self::Bot),
+            '[' => Some(Self::BoxLeft),
+            ']' => Some(Self::BoxRight),
+            _ => None,
+        }
+    }
+
+    fn from_char_fat(c: char) -> Option<(Self, Self)> {
+        match c {
+            '.' => Some((Self::Free, Self::Free)),
+            '#' => Some((Self::Wall, Self::Wall)),
+            'O' => Some((Self::BoxLeft, Self::BoxRight)),
+            '@' => Some((Self::Bot, Self::Free)),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Free => '.',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::Bot => '@',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+impl Sokoban {
+    fn from_lines<'a>(
+        input: &str,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParseError> {
+        Self::from_rows(input, lines, |line| {
+            parse::row(input, line, Tile::from_char)
+        })
+    }
+
+    fn from_lines_fat<'a>(
+        input: &str,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParseError> {
+        Self::from_rows(input, lines, |line| {
+            let pairs = parse::row(input, line, Tile::from_char_fat)?;
+
+            Ok(pairs.into_iter().flat_map(|(left, right)| [left, right]).collect())
+        })
+    }
+
+    /// Reads rows up to the blank line, making sure they're all as wide
+    fn from_rows<'a>(
+        input: &str,
+        lines: &mut impl Iterator<Item = &'a str>,
+        mut row: impl FnMut(&str) -> Result<Vec<Tile>, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut tiles = vec![];
+
+        for line in lines {
+            if line.is_empty() {
+                break;
+            }
+
+            let row = row(line)?;
+
+            if height > 0 && row.len() != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "the warehouse isn't a rectangle",
+                ));
+            }
+
+            height += 1;
+            width = row.len();
+            tiles.extend(row);
+        }
+
+        if !tiles.contains(&Tile::Bot) {
+            return Err(ParseError::eof(input, "the warehouse has no @ robot in it"));
+        }
+
+        Ok(Self {
+            tiles: Grid::new(width, height, tiles),
+        })
+    }
+
+    fn index(&self, coord: Coord) -> Tile {
+        self.tiles[coord].clone()
+    }
+
+    fn find_my_robot(&self) -> Coord {
+        self.tiles
+            .find(|tile| matches!(tile, Tile::Bot))
+            .expect("Parsing makes sure there's a robot man")
+    }
+
+    fn poosh(&mut self, moveset: &Moveset) {
+        moveset.moves.iter().for_each(|mov| self.push(*mov));
+    }
+
+    fn interactive(&mut self, moveset: &Moveset) {
+        for (index, mov) in moveset.moves.iter().enumerate() {
+            let next = &moveset
+                .moves
+                .get(index + 1)
+                .map(|mv| format!("{mv:?}"))
+                .unwrap_or(String::from("none"));
+
+            if index > 950 {
+                eprintln!("\u{1b}[2;1H");
+                eprintln!("{index} {mov:?} (next: {next})       ");
+                self.push(*mov);
+                eprint!("{self}");
+
+                std::io::stdin().read_line(&mut String::new()).unwrap();
+            }
+        }
+    }
+
+    fn play(&mut self) {
+        eprintln!("\u{1b}[2;1H");
+        eprint!("{self}");
+
+        loop {
+            let mov = loop {
+                let c = console::Term::stdout().read_key().unwrap();
+
+                match c {
+                    console::Key::ArrowLeft => break Direction::Left,
+                    console::Key::ArrowRight => break Direction::Right,
+                    console::Key::ArrowUp => break Direction::Up,
+                    console::Key::ArrowDown => break Direction::Down,
+                    console::Key::Escape | console::Key::CtrlC => return,
+                    _ => {}
+                }
+            };
+
+            eprintln!("\u{1b}[2;1H");
+            self.push(mov);
+            eprint!("{self}");
+        }
+    }
+
+    fn push(&mut self, direction: Direction) {
+        let root = self.find_my_robot();
+
+        self.shift_to(root, direction.delta());
+    }
+
+    fn shift_to(&mut self, from: Coord, delta: (isize, isize)) {
+        let mut patch_list = vec![(from, Tile::Free)];
+        let mut cleanup = vec![];
+        // "current tiles" which will be checked for free space in front
+        let mut current_list = vec![from];
+
+        loop {
+            let mut patches = vec![];
+
+            for current in current_list {
+                let current_tile = self.index(current);
+
+                let mut next = vec![];
+
+                if !matches!(current_tile, Tile::Free) {
+                    cleanup.push(current);
+
+                    next.push((
+                        (current.0 as isize + delta.0).try_into().ok().unwrap(),
+                        (current.1 as isize + delta.1).try_into().ok().unwrap(),
+                        current_tile.clone(),
+                    ));
+                }
+
+                match current_tile {
+                    Tile::BoxLeft if delta.1 != 0 => {
+                        cleanup.push((current.0 + 1, current.1));
+                        next.push((
+                            (current.0 as isize + delta.0 + 1).try_into().ok().unwrap(),
+                            (current.1 as isize + delta.1).try_into().ok().unwrap(),
+                            Tile::BoxRight,
+                        ));
+                    }
+                    Tile::BoxRight if delta.1 != 0 => {
+                        cleanup.push((current.0 - 1, current.1));
+                        next.push((
+                            (current.0 as isize + delta.0 - 1).try_into().ok().unwrap(),
+                            (current.1 as isize + delta.1).try_into().ok().unwrap(),
+                            Tile::BoxLeft,
+                        ))
+                    }
+                    _ => {}
+                }
+
+                if next.iter().any(|(x, y, _)| !self.tiles.contains((*x, *y))) {
+                    panic!("out of bounds lmao add walls to the edges you tool");
+                }
+
+                if next
+                    .iter()
+                    .any(|(x, y, _)| matches!(self.index((*x, *y)), Tile::Wall))
+                {
+                    // eprintln!("walled");
+                    return;
+                }
+
+                patches.extend(next);
+            }
+
+            current_list = patches.iter().map(|(x, y, _)| (*x, *y)).collect();
+
+            patch_list.extend(patches.into_iter().map(|(x, y, tile)| ((x, y), tile)));
+
+            let all_tiles_free = current_list
+                .iter()
+                .map(|check| self.index(*check))
+                .all(|check_tile| matches!(check_tile, Tile::Free));
+
+            if all_tiles_free {
+                break;
+            }
+        }
+
+        for coord in cleanup {
+            self.tiles[coord] = Tile::Free;
+        }
+
+        for (coord, tile) in patch_list {
+            self.tiles[coord] = tile;
+        }
+    }
+
+    fn sum(&self) -> u64 {
+        self.tiles.iter().fold(0, |sum, (pos, tile)| {
+            if let Tile::Box | Tile::BoxLeft = tile {
+                sum + pos.1 as u64 * 100 + pos.0 as u64
+            } else {
+                sum
+            }
+        })
+    }
+}
+
+impl Display for Sokoban {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.tiles.fmt(f)
+    }
+}
+
+impl Moveset {
+    fn from_lines<'a>(
+        input: &str,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParseError> {
+        let mut moves = vec![];
+
+        for line in lines {
+            moves.extend(parse::row(input, line, Direction::from_arrow)?);
+        }
+
+        Ok(Self { moves })
+    }
+}
+
+impl Warehouse {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input);
+        let sokoban = Sokoban::from_lines(input, &mut lines)?;
+        let moveset = Moveset::from_lines(input, &mut lines)?;
+        let fat_sokoban = Sokoban::from_lines_fat(input, &mut parse::lines(input))?;
+
+        Ok(Self {
+            sokoban,
+            fat_sokoban,
+            moveset,
+        })
+    }
+}
+
+/// A walled in warehouse `size` square, strewn with boxes and the odd wall,
+/// then a robot somewhere in it and ten moves per tile for it to make
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut tiles = Grid::filled(size, size, '#');
+    let inside: Vec<Coord> = tiles
+        .coords()
+        .filter(|&(x, y)| 0 < x && x < size - 1 && 0 < y && y < size - 1)
+        .collect();
+
+    for &coord in &inside {
+        tiles[coord] = match rng.below(20) {
+            0 => '#',
+            1..=4 => 'O',
+            _ => '.',
+        };
+    }
+
+    tiles[*rng.pick(&inside)] = '@';
+
+    let moves: Vec<char> = (0..size * size * 10)
+        .map(|_| *rng.pick(&['^', 'v', '<', '>']))
+        .collect();
+    let moves: Vec<String> = moves.chunks(70).map(String::from_iter).collect();
+
+    format!("{tiles}\n{}\n", moves.join("\n"))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = Warehouse;
+
+    fn parse(input: &str) -> Result<Warehouse, ParseError> {
+        Warehouse::from_str(input)
+    }
+
+    fn part1(warehouse: &Warehouse) -> Answer {
+        let mut sokoban = warehouse.sokoban.clone();
+
+        sokoban.poosh(&warehouse.moveset);
+
+        sokoban.sum().into()
+    }
+
+    fn part2(warehouse: &Warehouse) -> Answer {
+        let mut sokoban = warehouse.fat_sokoban.clone();
+        let moveset = &warehouse.moveset;
+
+        if std::env::args().any(|arg| arg == "--interactive") {
+            sokoban.interactive(moveset);
+        } else if std::env::args().any(|arg| arg == "--play") {
+            sokoban.play();
+        } else {
+            sokoban.poosh(moveset);
+        }
+
+        sokoban.sum().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_small() {
+        let input = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+        let mut lines = parse::lines(input);
+
+        let mut sokoban = Sokoban::from_lines(input, &mut lines).unwrap();
+        let moveset = Moveset::from_lines(input, &mut lines).unwrap();
+
+        sokoban.poosh(&moveset);
+
+        println!("{sokoban}");
+        assert_eq!(sokoban.sum(), 2028);
+    }
+
+    #[test]
+    fn example_fat() {
+        let input = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+        let mut lines = parse::lines(input);
+
+        let mut sokoban = Sokoban::from_lines_fat(input, &mut lines).unwrap();
+        let moveset = Moveset::from_lines(input, &mut lines).unwrap();
+
+        sokoban.poosh(&moveset);
+
+        println!("{sokoban}");
+        assert_eq!(sokoban.sum(), 618);
+    }
+
+    #[test]
+    fn example_big_fat() {
+        let input = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+        let mut lines = parse::lines(input);
+
+        let mut sokoban = Sokoban::from_lines_fat(input, &mut lines).unwrap();
+        let moveset = Moveset::from_lines(input, &mut lines).unwrap();
+
+        sokoban.poosh(&moveset);
+
+        println!("{sokoban}");
+        assert_eq!(sokoban.sum(), 9021);
+    }
+
+    #[test]
+    fn push_down() {
+        let input = "\
+############
+##....[][]##
+##..@..[].##
+##..[][][]##
+##...[][].##
+##[]......##
+##[]..[]..##
+##.[].[][]##
+##........##
+############
+
+^vvvv";
+        let mut lines = parse::lines(input);
+
+        let mut sokoban = Sokoban::from_lines(input, &mut lines).unwrap();
+        let moveset = Moveset::from_lines(input, &mut lines).unwrap();
+
+        sokoban.interactive(&moveset);
+
+        assert_eq!(sokoban.sum(), 5978);
+    }
+
+    #[test]
+    fn no_robot() {
+        let error = Warehouse::from_str("####\n#.O#\n####\n\n<>\n").err().unwrap();
+
+        assert_eq!(error.message, "the warehouse has no @ robot in it");
+        assert!(Warehouse::from_str("").is_err());
+    }
+
+    #[test]
+    fn generated_warehouses() {
+        for seed in 0..20 {
+            let size = 3 + seed as usize;
+            let input = generate(&mut Rng::new(seed), size);
+            let warehouse = Warehouse::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), size));
+            assert_eq!(warehouse.moveset.moves.len(), size * size * 10);
+
+            // Pushing the thin boxes the obvious way: find the end of the
+            // line, and if it's free, the first box jumps there
+            let mut tiles = warehouse.sokoban.tiles.clone();
+            let mut robot = warehouse.sokoban.find_my_robot();
+
+            for &direction in &warehouse.moveset.moves {
+                let mut end = tiles.step(robot, direction).unwrap();
+
+                while tiles[end] == Tile::Box {
+                    end = tiles.step(end, direction).unwrap();
+                }
+
+                if tiles[end] == Tile::Free {
+                    let next = tiles.step(robot, direction).unwrap();
+
+                    tiles[end] = Tile::Box;
+                    tiles[next] = Tile::Bot;
+                    tiles[robot] = Tile::Free;
+                    robot = next;
+                }
+            }
+
+            let mut sokoban = warehouse.sokoban.clone();
+
+            sokoban.poosh(&warehouse.moveset);
+
+            assert_eq!(sokoban.tiles.cells(), tiles.cells(), "seed {seed}");
+
+            // The fat boxes should all still be in one piece, and none lost
+            let mut fat = warehouse.fat_sokoban.clone();
+            let count = |sokoban: &Sokoban, tile| {
+                sokoban.tiles.cells().iter().filter(|&t| *t == tile).count()
+            };
+            let boxes = count(&fat, Tile::BoxLeft);
+
+            fat.poosh(&warehouse.moveset);
+
+            assert_eq!(count(&fat, Tile::BoxLeft), boxes, "seed {seed}");
+            assert_eq!(count(&fat, Tile::Bot), 1, "seed {seed}");
+            assert!(fat.tiles.iter().all(|(coord, tile)| {
+                *tile != Tile::BoxLeft || fat.tiles[(coord.0 + 1, coord.1)] == Tile::BoxRight
+            }));
+        }
+    }
+}
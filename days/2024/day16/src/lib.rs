@@ -0,0 +1,430 @@
+use std::collections::HashSet;
+
+use aoc::{Answer, ParseError, Rng, Solution};
+use grid::{Coord, Direction, Grid};
+use search::Search;
+
+pub struct Maze {
+    tiles: Grid<Tile>,
+}
+
+/// Where we are and which way we're facing
+type Reindeer = (Coord, Direction);
+
+#[derive(Clone)]
+enum Tile {
+    Wall,
+    Free,
+    Start,
+    End,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Self::Wall),
+            '.' => Some(Self::Free),
+            'S' => Some(Self::Start),
+            'E' => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+
+impl Maze {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, Tile::from_char)?;
+
+        if tiles.find(|tile| matches!(tile, Tile::Start)).is_none() {
+            return Err(ParseError::eof(input, "the maze has no S to start from"));
+        }
+
+        if tiles.find(|tile| matches!(tile, Tile::End)).is_none() {
+            return Err(ParseError::eof(input, "the maze has no E to get to"));
+        }
+
+        let maze = Self { tiles };
+        let start = (maze.stocate_lart(), Direction::Right);
+        let end = maze.truncatend();
+        // Only the turns the reindeer can actually make count, so a start
+        // that's backed into a dead end is as stuck as a walled off E
+        let reachable = Search::new().bfs(
+            [start],
+            |reindeer: &Reindeer| maze.adj(reindeer).map(|(next, _)| next),
+            |(coord, _)| *coord == end,
+        );
+
+        if reachable.is_none() {
+            let at = input.find('E').expect("We just found the E man");
+            return Err(ParseError::at(
+                input,
+                &input[at..at + 1],
+                "there's no way from S to this E",
+            ));
+        }
+
+        Ok(maze)
+    }
+
+    /// Straight on for 1, or turning and stepping for 1001, as long as
+    /// there's no wall in the way
+    fn adj(&self, &(coord, dir): &Reindeer) -> impl Iterator<Item = (Reindeer, u64)> + '_ {
+        [dir.turn_left(), dir, dir.turn_right()]
+            .into_iter()
+            .filter_map(move |erection| {
+                let next = self.tiles.step(coord, erection)?;
+                let cost = if dir == erection { 1 } else { 1001 };
+
+                if matches!(self.tiles[next], Tile::Wall) {
+                    return None;
+                }
+
+                log::trace!("{cost} {dir:?} {erection:?} {next:?}");
+
+                Some(((next, erection), cost))
+            })
+    }
+
+    fn stocate_lart(&self) -> Coord {
+        self.tiles.find(|tile| matches!(tile, Tile::Start)).unwrap()
+    }
+
+    fn truncatend(&self) -> Coord {
+        self.tiles.find(|tile| matches!(tile, Tile::End)).unwrap()
+    }
+
+    /// The lowest score, and the search that found it, which knows every
+    /// way of getting that score
+    fn pind(&self) -> (u64, Search<Reindeer, u64>) {
+        let start = (self.stocate_lart(), Direction::Right);
+        let end = self.truncatend();
+        let mut search = Search::new();
+
+        let (_, cost) = search
+            .dijkstra(
+                [start],
+                |reindeer: &Reindeer| self.adj(reindeer),
+                |(coord, _)| *coord == end,
+            )
+            .expect("Parsing makes sure there's a way through man");
+
+        log::debug!("reached end for {cost}");
+
+        (cost, search)
+    }
+
+    fn tile_count(&self) -> u64 {
+        let (best, search) = self.pind();
+        let end = self.truncatend();
+        // Whichever way we end up facing, as long as it's for the best score
+        let ends = Direction::ALL
+            .into_iter()
+            .map(|facing| (end, facing))
+            .filter(|reindeer| search.cost(reindeer) == Some(best));
+
+        let tiles: HashSet<_> = search
+            .on_shortest_paths(ends)
+            .into_iter()
+            .map(|(coord, _)| coord)
+            .collect();
+
+        tiles.len() as u64
+    }
+}
+
+/// A square maze about `size` wide, with S in the bottom left corner and E
+/// in the top right. It starts out as a proper maze, so there's always a
+/// way through, and then loses some walls so there's more than one.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    // Paths run along the odd rows and columns, with walls in between
+    let side = size.max(5) | 1;
+    let mut open = vec![false; side * side];
+    let start = (1, side - 2);
+    let mut stack = vec![start];
+
+    open[start.0 + start.1 * side] = true;
+
+    while let Some(&(x, y)) = stack.last() {
+        let mut steps: Vec<(isize, isize)> = vec![(0, -2), (0, 2), (-2, 0), (2, 0)];
+
+        rng.shuffle(&mut steps);
+
+        let next = steps.into_iter().find_map(|(dx, dy)| {
+            let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+            (next.0 < side - 1 && next.1 < side - 1 && !open[next.0 + next.1 * side])
+                .then_some(next)
+        });
+
+        match next {
+            Some(next) => {
+                open[(x + next.0) / 2 + (y + next.1) / 2 * side] = true;
+                open[next.0 + next.1 * side] = true;
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // Walls between two paths can go without opening up anything weird
+    for _ in 0..side * side / 40 {
+        let (x, y) = (rng.range(1..side - 1), rng.range(1..side - 1));
+
+        if (x + y) % 2 == 1 {
+            open[x + y * side] = true;
+        }
+    }
+
+    let mut maze = String::new();
+
+    for y in 0..side {
+        for x in 0..side {
+            maze.push(match (x, y) {
+                _ if (x, y) == start => 'S',
+                _ if (x, y) == (side - 2, 1) => 'E',
+                _ if open[x + y * side] => '.',
+                _ => '#',
+            });
+        }
+
+        maze.push('\n');
+    }
+
+    maze
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Maze;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        Maze::from_str(input)
+    }
+
+    fn part1(maze: &Maze) -> Answer {
+        maze.pind().0.into()
+    }
+
+    fn part2(maze: &Maze) -> Answer {
+        maze.tile_count().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::Reverse,
+        collections::{BinaryHeap, HashMap},
+    };
+
+    use super::*;
+
+    #[test]
+    fn micro() {
+        let maze = Maze::from_str(
+            "\
+####
+#.E#
+#S.#
+####",
+        )
+        .unwrap();
+
+        let results = maze.pind();
+        assert_eq!(results.0, 1002);
+        // Right then up, as going up first means turning twice
+        assert_eq!(maze.tile_count(), 3);
+    }
+
+    #[test]
+    fn mini() {
+        let maze = Maze::from_str(
+            "\
+#####
+#..E#
+#.#.#
+#S..#
+#####",
+        )
+        .unwrap();
+
+        let results = maze.pind();
+        assert_eq!(results.0, 1004);
+        assert_eq!(maze.tile_count(), 5);
+    }
+
+    #[test]
+    fn mini_twice() {
+        let maze = Maze::from_str(
+            "\
+#####
+#.#E#
+#.#.#
+#...#
+#.#.#
+#...#
+#S#.#
+#####",
+        )
+        .unwrap();
+
+        let results = maze.pind();
+        assert_eq!(results.0, 3007);
+        // Along row 5 or row 3 both cost 3007, so both count
+        assert_eq!(maze.tile_count(), 11);
+    }
+
+    #[test]
+    fn walled_off() {
+        let error = Maze::from_str(
+            "\
+#####
+#S#E#
+#####",
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 4, "E"));
+        assert_eq!(error.message, "there's no way from S to this E");
+
+        // Facing right into a wall with the way out behind us
+        let error = Maze::from_str(
+            "\
+#####
+#ES.#
+#####",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.message, "there's no way from S to this E");
+    }
+
+    #[test]
+    fn example_one() {
+        let maze = Maze::from_str(
+            "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############",
+        )
+        .unwrap();
+
+        let results = maze.pind();
+        assert_eq!(results.0, 7036);
+        assert_eq!(maze.tile_count(), 45);
+    }
+
+    #[test]
+    fn example_two() {
+        let maze = Maze::from_str(
+            "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################",
+        )
+        .unwrap();
+
+        assert_eq!(maze.pind().0, 11048);
+        assert_eq!(maze.tile_count(), 64);
+    }
+
+    /// The lowest score and how many tiles are on a path that gets it, by
+    /// running Dijkstra both ways over (tile, facing) states
+    fn best_seats(input: &str) -> (u64, u64) {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let find = |c| {
+            (0..rows.len())
+                .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+                .find(|&(x, y)| rows[y][x] == c)
+                .unwrap()
+        };
+        // East, south, west, north
+        let steps = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+        let dijkstra = |starts: Vec<(usize, usize, usize)>, backwards: bool| {
+            let mut costs = HashMap::new();
+            let mut queue: BinaryHeap<_> =
+                starts.into_iter().map(|state| Reverse((0, state))).collect();
+
+            while let Some(Reverse((cost, (x, y, d)))) = queue.pop() {
+                if costs.contains_key(&(x, y, d)) {
+                    continue;
+                }
+
+                costs.insert((x, y, d), cost);
+
+                let (dx, dy) = steps[d];
+                let (dx, dy) = if backwards { (-dx, -dy) } else { (dx, dy) };
+                let (nx, ny) = (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+
+                if rows[ny][nx] != b'#' {
+                    queue.push(Reverse((cost + 1, (nx, ny, d))));
+                }
+
+                queue.push(Reverse((cost + 1000, (x, y, (d + 1) % 4))));
+                queue.push(Reverse((cost + 1000, (x, y, (d + 3) % 4))));
+            }
+
+            costs
+        };
+
+        let (start, end) = (find(b'S'), find(b'E'));
+        let there = dijkstra(vec![(start.0, start.1, 0)], false);
+        let back = dijkstra((0..4).map(|d| (end.0, end.1, d)).collect(), true);
+        let best = (0..4).map(|d| there[&(end.0, end.1, d)]).min().unwrap();
+        let seats: HashSet<_> = there
+            .iter()
+            .filter(|(state, cost)| back.get(state).is_some_and(|back| *cost + back == best))
+            .map(|((x, y, _), _)| (x, y))
+            .collect();
+
+        (best, seats.len() as u64)
+    }
+
+    #[test]
+    fn generated_mazes() {
+        for seed in 0..10 {
+            let input = generate(&mut Rng::new(seed), 15);
+            let maze = Maze::from_str(&input).unwrap();
+
+            assert_eq!(
+                (maze.pind().0, maze.tile_count()),
+                best_seats(&input),
+                "seed {seed}\n{input}"
+            );
+        }
+    }
+}
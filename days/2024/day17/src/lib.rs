@@ -0,0 +1,374 @@
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    pointer: usize,
+    registers: Registers,
+    instructions: Vec<Instruction>,
+}
+
+#[derive(Debug, Clone)]
+struct Registers {
+    // Registers can hold "any number", let's assume 64 bits are enough
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Instruction(u8);
+
+impl Registers {
+    fn from_lines<'a>(
+        input: &str,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Registers, ParseError> {
+        let mut a = None;
+        let mut b = None;
+        let mut c = None;
+
+        for line in lines {
+            if line.is_empty() {
+                break;
+            }
+
+            let (name, value) = scan::key_value(input, line)?;
+            let register = scan::line(input, name, |s| {
+                s.literal("Register ")?;
+                s.one_of(&["A", "B", "C"])
+            })?;
+            let value = scan::line(input, value, |s| s.unsigned())?;
+
+            match register {
+                "A" => a = Some(value),
+                "B" => b = Some(value),
+                _ => c = Some(value),
+            }
+        }
+
+        let missing =
+            |name: &str| ParseError::eof(input, format!("register {name} never showed up"));
+
+        Ok(Self {
+            a: a.ok_or_else(|| missing("A"))?,
+            b: b.ok_or_else(|| missing("B"))?,
+            c: c.ok_or_else(|| missing("C"))?,
+        })
+    }
+}
+
+impl Instruction {
+    fn new(n: u8) -> Option<Self> {
+        (n <= 7).then_some(Self(n))
+    }
+
+    fn parse(input: &str, s: &str) -> Result<Self, ParseError> {
+        let n = parse::number(input, s)?;
+
+        Self::new(n).ok_or_else(|| ParseError::at(input, s, "It's... it's too big, it won't fit"))
+    }
+}
+
+impl Program {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input);
+        let registers = Registers::from_lines(input, &mut lines)?;
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::eof(input, "expected a Program: line"))?;
+        let instructions = scan::line(input, line, |s| {
+            s.literal("Program: ")?;
+            s.list(",", |s| {
+                let digits = s
+                    .take_while(|c| c.is_ascii_digit())
+                    .ok_or_else(|| s.expected("an instruction"))?;
+
+                Instruction::parse(input, digits)
+            })
+        })?;
+
+        Ok(Self {
+            pointer: 0,
+            registers,
+            instructions,
+        })
+    }
+
+    fn read_instruction(&mut self) -> Option<Instruction> {
+        let ins = self.instructions.get(self.pointer)?;
+
+        self.pointer += 1;
+
+        Some(*ins)
+    }
+
+    fn read_literal(&mut self) -> Option<u8> {
+        let ins = self.read_instruction()?;
+
+        Some(ins.0)
+    }
+
+    fn read_combo(&mut self) -> Option<u64> {
+        let ins = self.read_instruction()?;
+
+        Some(match ins.0 {
+            0..=3 => ins.0 as u64,
+            4 => self.registers.a,
+            5 => self.registers.b,
+            6 => self.registers.c,
+            _ => unsafe { std::hint::unreachable_unchecked() },
+        })
+    }
+
+    fn tick(&mut self, output: &mut Vec<u8>) -> Option<()> {
+        let ins = self.read_instruction()?;
+
+        match ins {
+            // adv
+            Instruction(0) => {
+                let numerator = self.registers.a;
+                let denominator = 2u64.pow(self.read_combo()? as u32);
+
+                // truncate
+                self.registers.a = numerator / denominator;
+            }
+            // bxl
+            Instruction(1) => {
+                let mask = self.read_literal()? as u64;
+
+                self.registers.b ^= mask;
+            }
+            // bst
+            Instruction(2) => {
+                let truncated = self.read_combo()? % 8;
+
+                self.registers.b = truncated;
+            }
+            // jnz
+            Instruction(3) => {
+                // Always read 2 instructions if 0 or not
+                let literal = self.read_literal()?;
+
+                if self.registers.a != 0 {
+                    self.pointer = literal as usize;
+                }
+            }
+            // bxc
+            Instruction(4) => {
+                // For legacy reasons, read operand and drop it
+                let _shitty_arch = self.read_instruction()?;
+
+                self.registers.b ^= self.registers.c;
+            }
+            // out
+            Instruction(5) => {
+                let comboni = self.read_combo()? % 8;
+
+                output.push(comboni as u8);
+            }
+            // bdv
+            Instruction(6) => {
+                let numerator = self.registers.a;
+                let denominator = 2u64.pow(self.read_combo()? as u32);
+
+                // truncate
+                self.registers.b = numerator / denominator;
+            }
+            // cdv
+            Instruction(7) => {
+                let numerator = self.registers.a;
+                let denominator = 2u64.pow(self.read_combo()? as u32);
+
+                // truncate
+                self.registers.c = numerator / denominator;
+            }
+            _ => unsafe { std::hint::unreachable_unchecked() },
+        }
+
+        Some(())
+    }
+
+    fn process(&mut self, output: &mut Vec<u8>) {
+        // A program that jumps back forever never stops ticking
+        while self.tick(output).is_some() {
+            aoc::check_cancelled();
+        }
+    }
+
+    fn find_quine(&mut self) -> u64 {
+        let mut output = Vec::new();
+
+        // Given pux's program analysis, only three bits at a time matter
+        // So we can """brute force""" 3 bit numbers at a time
+        let mut triptetmocoquecahedrons = vec![0; self.instructions.len()];
+        let mut i = triptetmocoquecahedrons.len() - 1;
+
+        loop {
+            aoc::check_cancelled();
+
+            if triptetmocoquecahedrons[i] > 7 {
+                triptetmocoquecahedrons[i] = 0;
+                i += 1;
+                triptetmocoquecahedrons[i] += 1;
+
+                continue;
+            }
+
+            // We can shift right-to-left the currently resolved digits
+            // and they will add up to an exploratory register that we
+            // can use to see if our prefix will work to generate the next instruction
+            let register = triptetmocoquecahedrons
+                .iter()
+                .rev()
+                .take(self.instructions.len() - i)
+                .fold(0, |register, offset| (register << 3) + offset);
+
+            output.clear();
+            self.pointer = 0;
+            self.registers.a = register;
+
+            self.process(&mut output);
+
+            let suffix_lines_up = output.len() == self.instructions.len() - i
+                && output
+                    .iter()
+                    .zip(self.instructions.iter().skip(i))
+                    .all(|(o, i)| *o == i.0);
+
+            if suffix_lines_up && i == 0 {
+                return register;
+            }
+
+            if suffix_lines_up {
+                assert_ne!(i, 0);
+                i -= 1;
+            } else {
+                triptetmocoquecahedrons[i] += 1;
+            }
+        }
+    }
+}
+
+/// Whether some A makes `program` print itself, building it up three bits at
+/// a time from the end of the output like [`Program::find_quine`], but
+/// trying everything so it can say no
+fn has_quine(program: &mut Program, register: u64, matched: usize) -> bool {
+    let length = program.instructions.len();
+
+    if matched == length {
+        return true;
+    }
+
+    (0..8).any(|bits| {
+        let register = (register << 3) + bits;
+        let mut output = vec![];
+
+        program.pointer = 0;
+        program.registers.a = register;
+        program.process(&mut output);
+
+        output.len() == matched + 1
+            && output
+                .iter()
+                .zip(&program.instructions[length - matched - 1..])
+                .all(|(o, i)| *o == i.0)
+            && has_quine(program, register, matched + 1)
+    })
+}
+
+/// A program shaped like the real ones: a loop that mixes A's bottom three
+/// bits with some higher ones, prints that and shifts A along, with the
+/// constants and order picked so there's a quine to find. A starts out
+/// `size` octal digits long.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let digits = size.clamp(1, 21) as u32;
+    let a = rng.range(8usize.pow(digits - 1)..8usize.pow(digits).min(usize::MAX >> 1));
+
+    loop {
+        let mut middle = [
+            format!("1,{}", rng.below(8)),
+            format!("4,{}", rng.below(8)),
+            "0,3".to_string(),
+        ];
+
+        rng.shuffle(&mut middle);
+
+        let program = format!("2,4,1,{},7,5,{},5,5,3,0", rng.below(8), middle.join(","));
+        let input =
+            format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n");
+        let mut parsed =
+            Program::from_str(&input).expect("Made up a program that doesn't parse man");
+
+        if has_quine(&mut parsed, 0, 0) {
+            return input;
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Program;
+
+    fn parse(input: &str) -> Result<Program, ParseError> {
+        Program::from_str(input)
+    }
+
+    fn part1(program: &Program) -> Answer {
+        let mut program = program.clone();
+        let mut output = Vec::new();
+
+        program.process(&mut output);
+
+        let output = output
+            .into_iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        output.into()
+    }
+
+    fn part2(program: &Program) -> Answer {
+        let mut program = program.clone();
+
+        program.find_quine().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_programs() {
+        for seed in 0..20 {
+            let size = 1 + seed as usize;
+            let input = generate(&mut Rng::new(seed), size);
+            let mut program = Program::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), size));
+
+            // Every loop prints something and knocks an octal digit off A
+            let mut output = vec![];
+
+            program.clone().process(&mut output);
+            assert_eq!(output.len(), size, "seed {seed}");
+
+            let quine = program.find_quine();
+
+            output.clear();
+            program.pointer = 0;
+            program.registers.a = quine;
+            program.process(&mut output);
+
+            let instructions: Vec<_> = program.instructions.iter().map(|i| i.0).collect();
+
+            assert_eq!(output, instructions, "seed {seed}");
+        }
+    }
+}
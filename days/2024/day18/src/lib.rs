@@ -0,0 +1,278 @@
+use aoc::{command, parse, Answer, Command, ParseError, Rng, Solution};
+use grid::{Coord, Grid};
+use search::Search;
+
+#[derive(Debug, Clone)]
+pub struct Ram {
+    fallen: usize,
+    bytes: Vec<Coord>,
+    tiles: Grid<Tile>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Tile {
+    Free,
+    Byte,
+}
+
+impl Ram {
+    fn from_str(input: &str, width: usize, height: usize) -> Result<Self, ParseError> {
+        let bytes = parse::lines(input)
+            .map(|line| {
+                let (x, y) = line
+                    .split_once(",")
+                    .ok_or_else(|| ParseError::at(input, line, "bytes look like 6,9"))?;
+                let (x, y) = (parse::number(input, x)?, parse::number(input, y)?);
+
+                if x >= width || y >= height {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        "that byte falls outside of memory",
+                    ));
+                }
+
+                Ok((x, y))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if bytes.is_empty() {
+            return Err(ParseError::eof(input, "expected some bytes to fall"));
+        }
+
+        Ok(Self {
+            fallen: 0,
+            bytes,
+            tiles: Grid::filled(width, height, Tile::Free),
+        })
+    }
+
+    fn fall(&mut self, count: usize) {
+        for byte in self.bytes.iter().skip(self.fallen).take(count) {
+            self.tiles[*byte] = Tile::Byte;
+        }
+
+        self.fallen += count;
+    }
+
+    fn start(&self) -> Coord {
+        // habit
+        (0, 0)
+    }
+
+    fn end(&self) -> Coord {
+        // stay high
+        (self.tiles.width() - 1, self.tiles.height() - 1)
+    }
+
+    // Pathfinding 3.0
+    fn shortest_path(&self) -> Vec<Coord> {
+        self.shortest_path_reusing(&mut Search::new())
+    }
+
+    /// The way out, not counting where we start, or nothing if there isn't
+    /// one. Reuses `search` so trying again after every byte stays cheap.
+    fn shortest_path_reusing(&self, search: &mut Search<Coord, usize>) -> Vec<Coord> {
+        let end = self.end();
+        let free = |coord: &Coord| {
+            self.tiles
+                .neighbours(*coord)
+                .filter(|next| !matches!(self.tiles[*next], Tile::Byte))
+        };
+
+        match search.bfs([self.start()], free, |coord| *coord == end) {
+            Some(_) => search.path(&end).split_off(1),
+            None => vec![],
+        }
+    }
+
+    fn fall_until_blocked(&mut self) -> (usize, Coord) {
+        let mut search = Search::new();
+        let mut ruta = self.shortest_path_reusing(&mut search);
+
+        for _ in 0.. {
+            let fuckbyte = self.bytes[self.fallen];
+            self.fall(1);
+
+            if !ruta.contains(&fuckbyte) {
+                continue;
+            }
+
+            ruta = self.shortest_path_reusing(&mut search);
+
+            if ruta.is_empty() {
+                return (self.fallen - 1, fuckbyte);
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+/// Bytes falling all over a 71 by 71 memory space in random order, up to
+/// the one that cuts the way out off and then `size` more. The first 1024
+/// always leave a way out, like the real thing.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut cells: Vec<Coord> = Grid::filled(71, 71, ())
+        .coords()
+        .filter(|&coord| coord != (0, 0) && coord != (70, 70))
+        .collect();
+
+    loop {
+        rng.shuffle(&mut cells);
+
+        let mut ram = Ram {
+            fallen: 0,
+            bytes: cells.clone(),
+            tiles: Grid::filled(71, 71, Tile::Free),
+        };
+
+        ram.fall(1024);
+
+        if ram.shortest_path().is_empty() {
+            continue;
+        }
+
+        let (index, _) = ram.fall_until_blocked();
+        let bytes: Vec<_> = cells
+            .iter()
+            .take(index + 1 + size.max(1))
+            .map(|(x, y)| format!("{x},{y}\n"))
+            .collect();
+
+        return bytes.concat();
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = Ram;
+
+    fn parse(input: &str) -> Result<Ram, ParseError> {
+        // 71 fucking tiles because it goes from 0..=70
+        Ram::from_str(input, 71, 71)
+    }
+
+    fn part1(ram: &Ram) -> Answer {
+        let mut ram = ram.clone();
+
+        ram.fall(1024);
+
+        ram.shortest_path().len().into()
+    }
+
+    fn part2(ram: &Ram) -> Answer {
+        let mut ram = ram.clone();
+
+        // safe start from p1
+        ram.fall(1024);
+
+        let (_index, byte) = ram.fall_until_blocked();
+
+        byte.into()
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![Command::new(
+            "bytes",
+            "bytes N: the shortest way out after N bytes fell",
+            |ram, args| {
+                let count = command::arg(args, 0, "a byte count")?;
+
+                if count > ram.bytes.len() {
+                    return Err(format!("only {} bytes ever fall", ram.bytes.len()));
+                }
+
+                let mut ram = ram.clone();
+
+                ram.fall(count);
+
+                match ram.shortest_path().len() {
+                    0 => Ok("no way out".to_string()),
+                    steps => Ok(steps.to_string()),
+                }
+            },
+        )]
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let mut ram = Ram::from_str(
+            "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+",
+            7,
+            7,
+        )
+        .unwrap();
+
+        ram.fall(12);
+
+        assert_eq!(ram.shortest_path().len(), 22);
+        assert_eq!(ram.fall_until_blocked(), (20, (6, 1)));
+    }
+
+    #[test]
+    fn empty_input() {
+        let error = Ram::from_str("", 7, 7).err().unwrap();
+
+        assert_eq!(error.message, "expected some bytes to fall");
+    }
+
+    #[test]
+    fn generated_bytes() {
+        for seed in 0..3 {
+            let input = generate(&mut Rng::new(seed), 100);
+            let ram = Ram::from_str(&input, 71, 71).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 100));
+
+            let index = ram.bytes.len() - 101;
+
+            assert!(index >= 1024, "seed {seed}");
+            assert_eq!(Day18::part2(&ram), ram.bytes[index].into(), "seed {seed}");
+
+            // Checked from scratch, the way out is there right up until it isn't
+            let mut before = ram.clone();
+
+            before.fall(index);
+            assert!(!before.shortest_path().is_empty(), "seed {seed}");
+            before.fall(1);
+            assert!(before.shortest_path().is_empty(), "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+use regex::Regex;
+
+pub struct Ojisan {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl Ojisan {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input);
+        let patterns = lines
+            .next()
+            .ok_or_else(|| ParseError::eof(input, "expected some towel patterns"))?
+            .split(", ")
+            .map(|s| s.to_string())
+            .collect();
+
+        match lines.next() {
+            Some("") => {}
+            Some(line) => return Err(ParseError::at(input, line, "expected a blank line")),
+            None => return Err(ParseError::eof(input, "expected some designs")),
+        }
+
+        let designs = lines.map(|line| line.to_string()).collect();
+
+        Ok(Self { patterns, designs })
+    }
+
+    fn possible_count(&self) -> usize {
+        let pat = format!("^({})+$", self.patterns.join("|"));
+
+        let rex = Regex::new(&pat).unwrap();
+        self.designs.iter().filter(|des| rex.is_match(des)).count()
+    }
+
+    fn possibilities_count(&self) -> usize {
+        // The same leftovers come up across designs, and it only holds for
+        // these patterns, so it lives as long as this count does
+        let mut memo = HashMap::new();
+
+        self.designs
+            .iter()
+            .map(|des| self.count_possibilities(des, &mut memo))
+            .sum()
+    }
+
+    fn count_possibilities<'d>(
+        &self,
+        precinct: &'d str,
+        memo: &mut HashMap<&'d str, usize>,
+    ) -> usize {
+        if let Some(&count) = memo.get(precinct) {
+            return count;
+        }
+
+        let count = self
+            .patterns
+            .iter()
+            .map(|pat| match precinct.strip_prefix(pat.as_str()) {
+                Some("") => 1,
+                Some(rest) => self.count_possibilities(rest, memo),
+                None => 0,
+            })
+            .sum();
+
+        memo.insert(precinct, count);
+
+        count
+    }
+}
+
+/// Forty towel patterns, missing one of the colours on its own so not
+/// everything can be made, then `size` designs. Half are strung together
+/// from the patterns and the rest are just random stripes, which mostly
+/// can't be.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let colours = ['w', 'u', 'b', 'r', 'g'];
+    let missing = *rng.pick(&colours);
+    let mut patterns = vec![];
+
+    while patterns.len() < 40 {
+        let pattern: String = (0..rng.range(1..9)).map(|_| *rng.pick(&colours)).collect();
+
+        if pattern != missing.to_string() && !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+
+    let designs: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            if rng.chance(0.5) {
+                (0..rng.range(4..10)).map(|_| rng.pick(&patterns).as_str()).collect()
+            } else {
+                (0..rng.range(20..40)).map(|_| *rng.pick(&colours)).collect()
+            }
+        })
+        .collect();
+
+    format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n"))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = Ojisan;
+
+    fn parse(input: &str) -> Result<Ojisan, ParseError> {
+        Ojisan::from_str(input)
+    }
+
+    fn part1(ojisan: &Ojisan) -> Answer {
+        ojisan.possible_count().into()
+    }
+
+    fn part2(ojisan: &Ojisan) -> Answer {
+        ojisan.possibilities_count().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let ojisan = Ojisan::from_str(
+            "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb",
+        )
+        .unwrap();
+
+        assert_eq!(ojisan.possible_count(), 6);
+        assert_eq!(ojisan.possibilities_count(), 16);
+    }
+
+    #[test]
+    fn generated_towels() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), 50);
+            let ojisan = Ojisan::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 50));
+            assert_eq!((ojisan.patterns.len(), ojisan.designs.len()), (40, 50));
+
+            // Counting ways to make each design from the back, one spot at a time
+            let ways: Vec<usize> = ojisan
+                .designs
+                .iter()
+                .map(|design| {
+                    let mut ways = vec![0; design.len() + 1];
+
+                    ways[design.len()] = 1;
+
+                    for i in (0..design.len()).rev() {
+                        ways[i] = ojisan
+                            .patterns
+                            .iter()
+                            .filter(|pat| design[i..].starts_with(pat.as_str()))
+                            .map(|pat| ways[i + pat.len()])
+                            .sum();
+                    }
+
+                    ways[0]
+                })
+                .collect();
+
+            let possible = ways.iter().filter(|&&ways| ways > 0).count();
+
+            assert_eq!(ojisan.possible_count(), possible, "seed {seed}");
+            assert_eq!(
+                ojisan.possibilities_count(),
+                ways.iter().sum(),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use aoc::{command, parse, Answer, Command, ParseError, Rng, Solution, Variant};
+
+pub struct Reports {
+    lines: Vec<Line>,
+}
+
+#[derive(Clone)]
+struct Line {
+    values: Vec<i64>,
+}
+
+/// What counts as safe: every step the same way, between `min_step` and
+/// `max_step` big, after dropping up to `tolerance` levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub min_step: i64,
+    pub max_step: i64,
+    pub tolerance: usize,
+}
+
+impl Rules {
+    /// Part 1, no dampener
+    pub const STRICT: Rules = Rules {
+        min_step: 1,
+        max_step: 3,
+        tolerance: 0,
+    };
+
+    /// Part 2, the Problem Dampener lets one level go
+    pub const DAMPENED: Rules = Rules {
+        tolerance: 1,
+        ..Rules::STRICT
+    };
+
+    /// Whether going from `a` to `b` is fine for a report going `up` (or
+    /// down), and which rule it breaks if not
+    fn check(&self, a: i64, b: i64, up: bool) -> Result<(), Rule> {
+        let step = if up { b - a } else { a - b };
+
+        if step <= 0 {
+            Err(Rule::NotMonotonic)
+        } else if step > self.max_step {
+            Err(Rule::TooBig)
+        } else if step < self.min_step {
+            Err(Rule::TooSmall)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The ways a step between two levels can go wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// Flat, or going the other way from the first step
+    NotMonotonic,
+    /// More than `max_step`
+    TooBig,
+    /// Less than `min_step`, but at least going the right way
+    TooSmall,
+}
+
+/// Where a report first goes wrong, read as is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Problem {
+    /// The level the bad step lands on
+    pub index: usize,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnosis {
+    Safe,
+    /// Unsafe as is, but fine once the levels at `dropped` are gone
+    Dampened {
+        problem: Problem,
+        dropped: Vec<usize>,
+    },
+    /// Too broken for the tolerance to help
+    Unsafe {
+        problem: Problem,
+    },
+}
+
+impl Diagnosis {
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Diagnosis::Unsafe { .. })
+    }
+}
+
+/// Cheapest way found to keep a level, as the last one so far
+#[derive(Clone, Copy)]
+struct Kept {
+    /// Levels dropped so far
+    dropped: usize,
+    /// The level kept before this one, if any
+    from: Option<usize>,
+}
+
+impl Reports {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: parse::lines(input)
+                .map(|line| Line::from_str(input, line))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn safe_line_count(&self, rules: &Rules) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| line.diagnose(rules).is_safe())
+            .count()
+    }
+
+    fn brute_force_safe_line_count(&self) -> usize {
+        self.lines.iter().filter(|line| line.is_safe_dampened()).count()
+    }
+}
+
+impl Line {
+    fn from_str(input: &str, line: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            values: line
+                .split_whitespace()
+                .map(|n| parse::number(input, n))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn is_increasing(&self) -> bool {
+        self.values.windows(2).all(|a| a[0] < a[1])
+    }
+
+    fn is_decreasing(&self) -> bool {
+        self.values.windows(2).all(|a| a[0] > a[1])
+    }
+
+    fn is_stable(&self) -> bool {
+        self.values.windows(2).all(|a| (a[0] - a[1]).abs() <= 3)
+    }
+
+    pub fn is_safe(&self) -> bool {
+        (self.is_increasing() || self.is_decreasing()) && self.is_stable()
+    }
+
+    /// The old way of doing part 2, kept around to bench and test
+    /// [`Line::diagnose`] against
+    pub fn is_safe_dampened(&self) -> bool {
+        // Hacky try-all-removes, since implementing a skip and branch would take longer
+        // than the runtime of this function anyway
+        if self.is_safe() {
+            return true;
+        }
+
+        for i in 0..self.values.len() {
+            let mut mutated = self.clone();
+            mutated.values.remove(i);
+
+            if mutated.is_safe() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Works out whether the report is safe under `rules` in one go along the
+    /// levels, by keeping track of the fewest drops that leave each level as
+    /// the last one kept, going up and going down. Only the `tolerance`
+    /// levels before each one can come before it, so it's linear for a fixed
+    /// tolerance.
+    pub fn diagnose(&self, rules: &Rules) -> Diagnosis {
+        let values = &self.values;
+        let tolerance = rules.tolerance;
+        let mut problem = None;
+        // Indexed by level, then going up or not
+        let mut kept: Vec<[Option<Kept>; 2]> = Vec::with_capacity(values.len());
+
+        for (j, &b) in values.iter().enumerate() {
+            // Read as is, the first step decides which way it's meant to go
+            if j >= 1 && problem.is_none() {
+                let up = values[1] > values[0];
+
+                if let Err(rule) = rules.check(values[j - 1], b, up) {
+                    problem.get_or_insert(Problem { index: j, rule });
+                }
+            }
+
+            // Dropping everything before it, if that's allowed
+            let first = (j <= tolerance).then_some(Kept {
+                dropped: j,
+                from: None,
+            });
+            let mut best = [first, first];
+
+            for i in j.saturating_sub(tolerance + 1)..j {
+                let skipped = j - i - 1;
+
+                for (direction, up) in [(0, true), (1, false)] {
+                    let Some(before) = kept[i][direction] else {
+                        continue;
+                    };
+                    let dropped = before.dropped + skipped;
+
+                    if dropped > tolerance || rules.check(values[i], b, up).is_err() {
+                        continue;
+                    }
+
+                    if best[direction].is_none_or(|best| dropped < best.dropped) {
+                        best[direction] = Some(Kept {
+                            dropped,
+                            from: Some(i),
+                        });
+                    }
+                }
+            }
+
+            kept.push(best);
+        }
+
+        let Some(problem) = problem else {
+            return Diagnosis::Safe;
+        };
+
+        // Dropping everything after the last one kept counts too
+        let last = (0..values.len())
+            .flat_map(|i| [(i, 0), (i, 1)])
+            .filter_map(|(i, direction)| {
+                let dropped = kept[i][direction]?.dropped + values.len() - 1 - i;
+
+                (dropped <= tolerance).then_some((dropped, i, direction))
+            })
+            .min_by_key(|(dropped, _, _)| *dropped);
+
+        let Some((_, last, direction)) = last else {
+            return Diagnosis::Unsafe { problem };
+        };
+
+        let mut keeping = vec![false; values.len()];
+        let mut at = Some(last);
+
+        while let Some(i) = at {
+            keeping[i] = true;
+            at = kept[i][direction]
+                .expect("Came from a level that wasn't kept man")
+                .from;
+        }
+
+        let dropped = (0..values.len()).filter(|&i| !keeping[i]).collect();
+
+        Diagnosis::Dampened { problem, dropped }
+    }
+}
+
+/// `size` reports that start out safe, some of them with a level or two
+/// knocked out of place
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = String::new();
+
+    for _ in 0..size.max(1) {
+        let length = rng.range(5..9);
+        let up = rng.chance(0.5);
+        let mut level = rng.range(10..90) as i64;
+        let mut values = vec![];
+
+        for _ in 0..length {
+            values.push(level);
+
+            let step = rng.range(1..4) as i64;
+
+            level += if up { step } else { -step };
+        }
+
+        for _ in 0..rng.below(3) {
+            if rng.chance(0.4) {
+                let i = rng.below(length);
+
+                values[i] = rng.range(1..100) as i64;
+            }
+        }
+
+        let values: Vec<_> = values.iter().map(i64::to_string).collect();
+
+        lines.push_str(&values.join(" "));
+        lines.push('\n');
+    }
+
+    lines
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Reports;
+
+    fn parse(input: &str) -> Result<Reports, ParseError> {
+        Reports::from_str(input)
+    }
+
+    fn part1(reports: &Reports) -> Answer {
+        reports.safe_line_count(&Rules::STRICT).into()
+    }
+
+    fn part2(reports: &Reports) -> Answer {
+        reports.safe_line_count(&Rules::DAMPENED).into()
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant::new(2, "brute", |reports| {
+            reports.brute_force_safe_line_count().into()
+        })]
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![
+            Command::new(
+                "diagnose",
+                "diagnose N [K]: what's wrong with report N (from 1), dropping up to K (1) levels",
+                |reports, args| {
+                    let n: usize = command::arg(args, 0, "a report number")?;
+                    let tolerance = command::arg_or(args, 1, "a tolerance", 1)?;
+                    let line = n
+                        .checked_sub(1)
+                        .and_then(|i| reports.lines.get(i))
+                        .ok_or_else(|| format!("there are only {} reports", reports.lines.len()))?;
+                    let rules = Rules {
+                        tolerance,
+                        ..Rules::STRICT
+                    };
+
+                    Ok(format!("{:?}", line.diagnose(&rules)))
+                },
+            ),
+            Command::new(
+                "count",
+                "count [K MIN MAX]: how many reports are safe dropping up to K (1) levels, with steps from MIN (1) to MAX (3)",
+                |reports, args| {
+                    let rules = Rules {
+                        tolerance: command::arg_or(args, 0, "a tolerance", 1)?,
+                        min_step: command::arg_or(args, 1, "a step", 1)?,
+                        max_step: command::arg_or(args, 2, "a step", 3)?,
+                    };
+
+                    Ok(reports.safe_line_count(&rules).to_string())
+                },
+            ),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(s: &str) -> Line {
+        Line::from_str(s, s).unwrap()
+    }
+
+    fn dampened(s: &str, index: usize, rule: Rule, dropped: &[usize]) {
+        let diagnosis = line(s).diagnose(&Rules::DAMPENED);
+
+        assert_eq!(
+            diagnosis,
+            Diagnosis::Dampened {
+                problem: Problem { index, rule },
+                dropped: dropped.to_vec(),
+            },
+            "{s}"
+        );
+    }
+
+    #[test]
+    fn ejemplo() {
+        assert!(line("7 6 4 2 1").is_safe());
+        assert!(!line("1 2 7 8 9").is_safe());
+        assert!(!line("9 7 6 2 1").is_safe());
+        assert!(!line("1 3 2 4 5").is_safe());
+        assert!(!line("8 6 4 4 1").is_safe());
+        assert!(line("1 3 6 7 9").is_safe());
+    }
+
+    #[test]
+    fn diagnoses() {
+        use Rule::*;
+
+        let rules = Rules::DAMPENED;
+
+        assert_eq!(line("7 6 4 2 1").diagnose(&rules), Diagnosis::Safe);
+        assert_eq!(
+            line("1 2 7 8 9").diagnose(&rules),
+            Diagnosis::Unsafe {
+                problem: Problem {
+                    index: 2,
+                    rule: TooBig,
+                },
+            }
+        );
+        dampened("1 3 2 4 5", 2, NotMonotonic, &[2]);
+        dampened("8 6 4 4 1", 3, NotMonotonic, &[3]);
+        // Goes wrong at the 2, but it's the first 4 that has to go
+        dampened("4 1 2 3 4", 2, NotMonotonic, &[0]);
+        dampened("1 2 3 4 9", 4, TooBig, &[4]);
+
+        let picky = Rules {
+            min_step: 2,
+            max_step: 4,
+            tolerance: 2,
+        };
+
+        assert_eq!(line("1 3 7 9").diagnose(&picky), Diagnosis::Safe);
+        assert_eq!(
+            line("1 3 4 5 7").diagnose(&picky),
+            Diagnosis::Dampened {
+                problem: Problem {
+                    index: 2,
+                    rule: TooSmall,
+                },
+                dropped: vec![2],
+            }
+        );
+        assert!(!line("1 2 3 4 5 6").diagnose(&picky).is_safe());
+    }
+
+    #[test]
+    fn matches_trying_every_removal() {
+        let mut rng = Rng::new(2);
+
+        for _ in 0..2000 {
+            let length = rng.range(1..9);
+            let values: Vec<i64> = (0..length).map(|_| rng.range(0..12) as i64).collect();
+            let line = Line { values };
+
+            let diagnosis = line.diagnose(&Rules::DAMPENED);
+
+            assert_eq!(
+                diagnosis.is_safe(),
+                line.is_safe_dampened(),
+                "{:?}",
+                line.values
+            );
+            assert_eq!(
+                line.diagnose(&Rules::STRICT) == Diagnosis::Safe,
+                line.is_safe()
+            );
+
+            // Whatever it says to drop has to actually work
+            if let Diagnosis::Dampened { dropped, .. } = diagnosis {
+                let mut values = line.values.clone();
+
+                assert_eq!(dropped.len(), 1);
+                values.remove(dropped[0]);
+                assert!(Line { values }.is_safe());
+            }
+        }
+    }
+
+    #[test]
+    fn generated_reports() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), 200);
+            let reports = Reports::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 200));
+            assert_eq!(reports.lines.len(), 200);
+
+            let strict = reports.safe_line_count(&Rules::STRICT);
+            let dampened = reports.safe_line_count(&Rules::DAMPENED);
+
+            assert!(
+                0 < strict && strict < dampened && dampened < 200,
+                "seed {seed}"
+            );
+            assert_eq!(dampened, reports.brute_force_safe_line_count());
+        }
+    }
+}
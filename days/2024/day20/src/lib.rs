@@ -0,0 +1,300 @@
+use aoc::{command, Answer, Command, ParseError, Rng, Solution};
+use grid::{Coord, Grid};
+
+#[derive(Debug)]
+pub struct Code {
+    tiles: Grid<Tile>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Tile {
+    Free,
+    Wall,
+    Start,
+    End,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Tile::Free),
+            '#' => Some(Tile::Wall),
+            'S' => Some(Tile::Start),
+            'E' => Some(Tile::End),
+            _ => None,
+        }
+    }
+}
+
+impl Code {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(input, Tile::from_char)?;
+
+        for (tile, name) in [(Tile::Start, "S"), (Tile::End, "E")] {
+            if !tiles.cells().contains(&tile) {
+                return Err(ParseError::eof(
+                    input,
+                    format!("the racetrack has no {name}"),
+                ));
+            }
+        }
+
+        Ok(Self { tiles })
+    }
+
+    fn start(&self) -> Coord {
+        self.tiles
+            .find(|tile| *tile == Tile::Start)
+            .expect("Parsing makes sure there's an S man")
+    }
+
+    fn end(&self) -> Coord {
+        self.tiles
+            .find(|tile| *tile == Tile::End)
+            .expect("Parsing makes sure there's an E man")
+    }
+
+    fn path_lmao(&self) -> Vec<Coord> {
+        let start = self.start();
+        let end = self.end();
+
+        let mut coords = vec![];
+        let mut current = start;
+        let mut previous = start;
+
+        while current != end {
+            let next = self
+                .tiles
+                .neighbours(current)
+                .find(|next| *next != previous && !matches!(self.tiles[*next], Tile::Wall))
+                .unwrap();
+
+            coords.push(current);
+            previous = current;
+            current = next;
+        }
+
+        coords.push(current);
+
+        // only verify all free tiles are in path in debug builds
+        #[cfg(debug_assertions)]
+        for (coord, tile) in self.tiles.iter() {
+            if !matches!(tile, Tile::Wall) {
+                assert!(coords.contains(&coord));
+            }
+        }
+
+        coords
+    }
+
+    fn count_worthwhile_cheats(&self, min_savings: usize, max_distance: usize) -> i32 {
+        let path = self.path_lmao();
+
+        // Mapping beats a 9k cell scan
+        let mut indexes = Grid::filled(self.tiles.width(), self.tiles.height(), usize::MAX);
+        for (index, coord) in path.iter().enumerate() {
+            indexes[*coord] = index;
+        }
+
+        // score is len - 1; to beat is at least [min_savings] less than that
+        // even accounting for this +1 from including start pos, still need to use <=
+        let Some(score_to_beat) = path.len().checked_sub(min_savings) else {
+            // The whole track is shorter than that
+            return 0;
+        };
+
+        let mut hacks_beat = 0;
+
+        for (index, coord) in path.iter().enumerate() {
+            for (cheating_score, start) in self.tiles.within(*coord, max_distance) {
+                let end_index = indexes[start];
+
+                if end_index != usize::MAX {
+                    let score = index + cheating_score + (path.len() - end_index);
+
+                    if score <= score_to_beat {
+                        hacks_beat += 1;
+                    }
+                }
+            }
+        }
+
+        hacks_beat
+    }
+}
+
+/// A racetrack winding through a grid of `size` by `size` rooms with walls
+/// between them. It digs a random tree out from one room and keeps only the
+/// way to whichever room it got deepest into, so there's just the one track
+/// and it never runs alongside itself.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let rooms = size.max(2);
+    let start = (rng.below(rooms), rng.below(rooms));
+    let mut came_from: Grid<Option<Coord>> = Grid::filled(rooms, rooms, None);
+    let mut depth = Grid::filled(rooms, rooms, usize::MAX);
+    let mut digging = vec![start];
+    let mut deepest = start;
+
+    depth[start] = 0;
+
+    while let Some(&at) = digging.last() {
+        let next: Vec<_> = depth
+            .neighbours(at)
+            .filter(|next| depth[*next] == usize::MAX)
+            .collect();
+
+        if next.is_empty() {
+            digging.pop();
+
+            continue;
+        }
+
+        let next = *rng.pick(&next);
+
+        came_from[next] = Some(at);
+        depth[next] = depth[at] + 1;
+
+        if depth[next] > depth[deepest] {
+            deepest = next;
+        }
+
+        digging.push(next);
+    }
+
+    let mut tiles = Grid::filled(rooms * 2 + 1, rooms * 2 + 1, '#');
+    let room = |(x, y): Coord| (x * 2 + 1, y * 2 + 1);
+    let mut at = deepest;
+
+    tiles[room(at)] = 'E';
+
+    while let Some(from) = came_from[at] {
+        let ((ax, ay), (bx, by)) = (room(at), room(from));
+
+        tiles[((ax + bx) / 2, (ay + by) / 2)] = '.';
+        tiles[(bx, by)] = '.';
+        at = from;
+    }
+
+    tiles[room(at)] = 'S';
+
+    tiles.to_string()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = Code;
+
+    fn parse(input: &str) -> Result<Code, ParseError> {
+        Code::from_str(input)
+    }
+
+    fn part1(code: &Code) -> Answer {
+        code.count_worthwhile_cheats(100, 2).into()
+    }
+
+    fn part2(code: &Code) -> Answer {
+        code.count_worthwhile_cheats(100, 20).into()
+    }
+
+    fn commands() -> Vec<Command<Self>> {
+        vec![Command::new(
+            "cheat",
+            "cheat DISTANCE [SAVING]: cheats up to DISTANCE long that save SAVING (100) or more",
+            |code, args| {
+                let distance = command::arg(args, 0, "a distance")?;
+                let saving = command::arg_or(args, 1, "a saving", 100)?;
+
+                Ok(code.count_worthwhile_cheats(saving, distance).to_string())
+            },
+        )]
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let code = Code::from_str(
+            "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+",
+        )
+        .unwrap();
+
+        let path = code.path_lmao();
+
+        assert_eq!(path.len(), 85);
+
+        let cheat_count = code.count_worthwhile_cheats(64, 2);
+
+        assert_eq!(cheat_count, 1);
+    }
+
+    #[test]
+    fn no_start_or_end() {
+        let error = Code::from_str("#####\n#S..#\n#####\n").err().unwrap();
+
+        assert_eq!(error.message, "the racetrack has no E");
+        assert!(Code::from_str("#E.#\n").is_err());
+        assert!(Code::from_str("").is_err());
+    }
+
+    #[test]
+    fn generated_racetracks() {
+        for seed in 0..20 {
+            let size = 2 + seed as usize;
+            let input = generate(&mut Rng::new(seed), size);
+            let code = Code::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), size));
+            assert_eq!(code.tiles.width(), size * 2 + 1);
+
+            // Every pair of spots along the track, close enough to cut between
+            let path = code.path_lmao();
+            let cheats = |min_savings: usize, max_distance: usize| {
+                let mut count = 0;
+
+                for (i, a) in path.iter().enumerate() {
+                    for (j, b) in path.iter().enumerate().skip(i + 1) {
+                        let distance = a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+
+                        if distance <= max_distance && j - i >= min_savings + distance {
+                            count += 1;
+                        }
+                    }
+                }
+
+                count
+            };
+
+            for (min_savings, max_distance) in [(2, 2), (10, 2), (4, 20), (30, 20)] {
+                assert_eq!(
+                    code.count_worthwhile_cheats(min_savings, max_distance),
+                    cheats(min_savings, max_distance),
+                    "seed {seed}"
+                );
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
-const INPUT: &str = include_str!("../../../inputs/day22.txt");
+use aoc::{parse, Answer, ParseError, Rng, Solution, Variant};
 
-struct MonkeyBusiness {
+#[derive(Clone)]
+pub struct MonkeyBusiness {
     monkeys: Vec<u64>,
 }
 
@@ -23,10 +24,12 @@ fn prune(s: u64) -> u64 {
 }
 
 impl MonkeyBusiness {
-    fn from_str(input: &str) -> Self {
-        Self {
-            monkeys: input.lines().map(|line| line.parse().unwrap()).collect(),
-        }
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            monkeys: parse::lines(input)
+                .map(|line| parse::number(input, line))
+                .collect::<Result<_, _>>()?,
+        })
     }
 
     fn tick_all(&mut self, times: usize) {
@@ -42,7 +45,6 @@ impl MonkeyBusiness {
     // don't inline to aid in perf measurements
     // this function is still useful for higher values of `N`
     #[inline(never)]
-    #[allow(unused)]
     fn optimize_buy_sequence<const N: usize>(&self, times: usize) -> ([i8; N], u64) {
         let mut map = HashMap::new();
 
@@ -178,26 +180,45 @@ impl MonkeyBusiness {
     }
 }
 
-pub fn part1() {
-    let mut market = MonkeyBusiness::from_str(INPUT);
+/// `size` buyers, each with a secret number somewhere under the prune
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{}\n", rng.range(1..16777216)))
+        .collect()
+}
+
+pub struct Day22;
 
-    market.tick_all(2000);
+impl Solution for Day22 {
+    type Parsed<'a> = MonkeyBusiness;
 
-    dbg!(market.sum());
-}
+    fn parse(input: &str) -> Result<MonkeyBusiness, ParseError> {
+        MonkeyBusiness::from_str(input)
+    }
+
+    fn part1(market: &MonkeyBusiness) -> Answer {
+        let mut market = market.clone();
+
+        market.tick_all(2000);
 
-pub fn part2() {
-    let market = MonkeyBusiness::from_str(INPUT);
+        market.sum().into()
+    }
 
-    // let (optimal_sequence, optimal_price) = market.optimize_buy_sequence::<4>(2000);
+    fn part2(market: &MonkeyBusiness) -> Answer {
+        let (_optimal_sequence, optimal_price) = market.lemonize_buy_sequence::<4>(2000);
 
-    // eprintln!("optimal prices for optimal sequence {optimal_sequence:?} is {optimal_price}");
+        optimal_price.into()
+    }
 
-    // for _ in 0..100 {
-    let (optimal_sequence, optimal_price) = market.lemonize_buy_sequence::<4>(2000);
+    fn variants() -> Vec<Variant<Self>> {
+        vec![Variant::new(2, "hashmap", |market| {
+            market.optimize_buy_sequence::<4>(2000).1.into()
+        })]
+    }
 
-    eprintln!("lemonal prices for optimal sequence {optimal_sequence:?} is {optimal_price}");
-    // }
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -212,7 +233,8 @@ mod tests {
 10
 100
 2024",
-        );
+        )
+        .unwrap();
 
         market.tick_all(2000);
 
@@ -228,11 +250,28 @@ mod tests {
 2
 3
 2024",
-        );
+        )
+        .unwrap();
 
         let (optimal_sequence, optimal_price) = market.optimize_buy_sequence::<4>(2000);
 
         assert_eq!(optimal_sequence, [-2, 1, -1, 3]);
         assert_eq!(optimal_price, 23);
     }
+
+    #[test]
+    fn generated_buyers() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 20);
+            let market = MonkeyBusiness::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 20));
+            assert_eq!(market.monkeys.len(), 20);
+            assert_eq!(
+                market.lemonize_buy_sequence::<4>(2000).1,
+                market.optimize_buy_sequence::<4>(2000).1,
+                "seed {seed}"
+            );
+        }
+    }
 }
@@ -0,0 +1,297 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+
+#[derive(Debug)]
+pub struct LanParty<'a> {
+    map: HashMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> LanParty<'a> {
+    fn from_str(input: &'a str) -> Result<Self, ParseError> {
+        let mut map: HashMap<_, Vec<_>> = HashMap::new();
+
+        for line in parse::lines(input) {
+            let (left, right) = line
+                .split_once("-")
+                .ok_or_else(|| ParseError::at(input, line, "connections look like kh-tc"))?;
+
+            if left == right {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "a computer can't connect to itself",
+                ));
+            }
+
+            map.entry(left).or_default().push(right);
+            map.entry(right).or_default().push(left);
+        }
+
+        if map.is_empty() {
+            return Err(ParseError::eof(input, "expected some connections"));
+        }
+
+        Ok(Self { map })
+    }
+
+    fn group_sets(&'a self, lenis: usize) -> HashSet<Vec<&'a str>> {
+        let mut sets = HashSet::new();
+
+        fn explore_seq<'a>(
+            seq: Vec<&'a str>,
+            rest: &[&'a str],
+            map: &HashMap<&'a str, Vec<&'a str>>,
+            set: &mut HashSet<Vec<&'a str>>,
+            remaining: usize,
+        ) {
+            for next in rest {
+                if seq.contains(next) {
+                    continue;
+                }
+
+                let mut nseq = seq.clone();
+                nseq.push(next);
+
+                if remaining == 0 {
+                    if nseq.iter().all(|conn| {
+                        nseq.iter()
+                            .all(|other| other == conn || map.get(other).unwrap().contains(conn))
+                    }) {
+                        nseq.sort();
+                        set.insert(nseq);
+                    }
+                } else {
+                    let nrest = map.get(next).unwrap();
+
+                    explore_seq(nseq, nrest, map, set, remaining - 1);
+                }
+            }
+        }
+
+        for (&start, rest) in self.map.iter() {
+            let seq = vec![start];
+
+            explore_seq(seq, rest, &self.map, &mut sets, lenis - 2);
+        }
+
+        sets
+    }
+
+    fn largest_group(&'a self) -> Vec<Vec<&'a str>> {
+        let mut current_generation: HashMap<Vec<&'a str>, HashSet<&'a str>> = HashMap::new();
+
+        for (start, rest) in self.map.iter() {
+            current_generation.insert(vec![start], rest.iter().cloned().collect());
+        }
+
+        loop {
+            let mut next_generation: HashMap<Vec<&'a str>, HashSet<&'a str>> = HashMap::new();
+
+            log::debug!("{} groups still growing", current_generation.len());
+            for (prefix, possible_extensions) in current_generation.iter() {
+                log::trace!("{prefix:?} could take {}", possible_extensions.len());
+
+                for next in possible_extensions {
+                    let next_connections = self.map.get(next).unwrap();
+
+                    if prefix.iter().all(|prev| next_connections.contains(prev)) {
+                        let mut next_seq = prefix.clone();
+                        next_seq.push(next);
+                        next_seq.sort();
+
+                        let next_extensions =
+                            next_connections.iter().filter(|ext| !prefix.contains(ext));
+                        next_generation.entry(next_seq).or_default().extend(next_extensions);
+                    }
+                }
+            }
+
+            if next_generation.is_empty() {
+                return current_generation.keys().cloned().collect();
+            }
+
+            current_generation = next_generation;
+        }
+    }
+}
+
+/// `size` computers (there's only so many two letter names) with a couple of
+/// random connections each, and a LAN party of up to 13 of them hidden in
+/// there. Comes with the party's password, which is what part 2 should find
+/// once there are enough computers around for it to stand out.
+fn generate_network(rng: &mut Rng, size: usize) -> (String, String) {
+    let mut names: Vec<String> = ('a'..='z')
+        .flat_map(|a| ('a'..='z').map(move |b| format!("{a}{b}")))
+        .collect();
+
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(4, names.len()));
+
+    let mut party = names[..(names.len() / 4).clamp(4, 13)].to_vec();
+    let mut connections = HashSet::new();
+
+    for (i, a) in party.iter().enumerate() {
+        for b in &party[i + 1..] {
+            connections.insert((a.min(b), a.max(b)));
+        }
+    }
+
+    for a in &names {
+        for _ in 0..2 {
+            let b = rng.pick(&names);
+
+            if a != b {
+                connections.insert((a.min(b), a.max(b)));
+            }
+        }
+    }
+
+    // The set's order isn't up to the seed
+    let mut connections: Vec<_> = connections.into_iter().collect();
+
+    connections.sort();
+    rng.shuffle(&mut connections);
+
+    let lines: Vec<_> = connections
+        .into_iter()
+        .map(|(a, b)| {
+            if rng.chance(0.5) {
+                format!("{a}-{b}\n")
+            } else {
+                format!("{b}-{a}\n")
+            }
+        })
+        .collect();
+
+    party.sort();
+
+    (lines.concat(), party.join(","))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = LanParty<'a>;
+
+    fn parse(input: &str) -> Result<LanParty<'_>, ParseError> {
+        LanParty::from_str(input)
+    }
+
+    fn part1(party: &LanParty) -> Answer {
+        let mut sets_of_three = party.group_sets(3);
+
+        // filter the trios to a t
+        sets_of_three.retain(|menage_a_trois| {
+            menage_a_trois.iter().any(|computer| computer.starts_with("t"))
+        });
+
+        sets_of_three.len().into()
+    }
+
+    fn part2(party: &LanParty) -> Answer {
+        let lorgest = party.largest_group();
+        let password: String = lorgest.first().unwrap().join(",");
+
+        // lmao
+        // for i in 3.. {
+        //     let sets = party.group_sets(i);
+
+        //     dbg!(sets.len());
+        // }
+
+        password.into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate_network(rng, size).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let party = LanParty::from_str(
+            "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn",
+        )
+        .unwrap();
+
+        // dbg!(party);
+        dbg!(party.group_sets(3));
+        dbg!(party.group_sets(3).len());
+        dbg!(party.largest_group());
+    }
+
+    #[test]
+    fn empty_input() {
+        let error = LanParty::from_str("").err().unwrap();
+
+        assert_eq!(error.message, "expected some connections");
+    }
+
+    #[test]
+    fn generated_networks() {
+        for seed in 0..5 {
+            let (input, password) = generate_network(&mut Rng::new(seed), 200);
+            let party = LanParty::from_str(&input).unwrap();
+
+            assert_eq!(input, generate_network(&mut Rng::new(seed), 200).0);
+            assert_eq!(Day23::part2(&party), password.into(), "seed {seed}");
+
+            // Every trio, the slow way
+            let mut computers: Vec<_> = party.map.keys().copied().collect();
+            let connected = |a: &str, b: &str| party.map[a].contains(&b);
+            let mut trios = 0;
+
+            computers.sort();
+
+            for (i, a) in computers.iter().enumerate() {
+                for (j, b) in computers.iter().enumerate().skip(i + 1) {
+                    for c in &computers[j + 1..] {
+                        let t = [a, b, c].iter().any(|name| name.starts_with('t'));
+
+                        if t && connected(a, b) && connected(b, c) && connected(a, c) {
+                            trios += 1;
+                        }
+                    }
+                }
+            }
+
+            assert_eq!(Day23::part1(&party), trios.into(), "seed {seed}");
+        }
+    }
+}
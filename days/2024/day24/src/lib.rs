@@ -1,14 +1,16 @@
+#![allow(unused, clippy::needless_range_loop)]
+
 use std::{
     cmp::Reverse,
     collections::{HashMap, HashSet},
+    fmt::Write,
 };
 
-use regex::Regex;
-
-const INPUT: &str = include_str!("../../../inputs/day24.txt");
+use aoc::{command, parse, Answer, Command, ParseError, Rng, Solution};
+use scan::Scanner;
 
 #[derive(Debug, Clone)]
-struct Wirings<'a> {
+pub struct Wirings<'a> {
     wires: HashMap<&'a str, bool>,
     terms: Vec<Term<'a>>,
     remaining_terms: Vec<Term<'a>>,
@@ -22,7 +24,7 @@ struct Term<'a> {
     op: Op,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Op {
     And,
     Or,
@@ -39,7 +41,7 @@ impl Op {
         }
     }
 
-    fn to_str(&self) -> &'static str {
+    fn to_str(self) -> &'static str {
         match self {
             Op::And => "&",
             Op::Or => "|",
@@ -57,10 +59,8 @@ impl Op {
 }
 
 impl<'a> Wirings<'a> {
-    fn from_str(input: &'a str) -> Self {
-        let seed_regex = Regex::new(r"^(\w+): ([01])$").unwrap();
-        let term_regex = Regex::new(r"^(\w+) (AND|OR|XOR) (\w+) -> (\w+)").unwrap();
-        let mut lines = input.lines();
+    fn from_str(input: &'a str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input);
         let mut wires = HashMap::new();
         let mut terms = Vec::new();
 
@@ -69,33 +69,38 @@ impl<'a> Wirings<'a> {
                 break;
             }
 
-            let seed_caps = seed_regex.captures(line).unwrap();
-            let seed_wire = seed_caps.get(1).unwrap().as_str();
-            let seed_value = seed_caps.get(2).unwrap().as_str() == "1";
+            let (seed_wire, seed_value) = scan::key_value(input, line)?;
+            let seed_wire = scan::line(input, seed_wire, Scanner::word)?;
+            let seed_value = scan::line(input, seed_value, |s| s.one_of(&["0", "1"]))?;
 
-            wires.insert(seed_wire, seed_value);
+            wires.insert(seed_wire, seed_value == "1");
         }
 
         for line in &mut lines {
-            let term_caps = term_regex.captures(line).unwrap();
-            let term_dep_a = term_caps.get(1).unwrap().as_str();
-            let term_op = term_caps.get(2).unwrap().as_str();
-            let term_dep_b = term_caps.get(3).unwrap().as_str();
-            let term_wire = term_caps.get(4).unwrap().as_str();
-
-            terms.push(Term {
-                a: term_dep_a,
-                b: term_dep_b,
-                out: term_wire,
-                op: Op::from_str(term_op),
-            });
+            let term = scan::line(input, line, |s| {
+                let a = s.word()?;
+                s.literal(" ")?;
+                let op = s.one_of(&["AND", "OR", "XOR"])?;
+                s.literal(" ")?;
+                let b = s.word()?;
+                s.literal(" -> ")?;
+
+                Ok(Term {
+                    a,
+                    b,
+                    out: s.word()?,
+                    op: Op::from_str(op),
+                })
+            })?;
+
+            terms.push(term);
         }
 
-        Self {
+        Ok(Self {
             wires,
             terms: terms.clone(),
             remaining_terms: terms,
-        }
+        })
     }
 
     fn solve(&mut self) {
@@ -118,7 +123,7 @@ impl<'a> Wirings<'a> {
             let final_solved = self.wires.len();
             let delta_solved = final_solved - initial_solved;
 
-            // eprintln!("solved {delta_solved} terms");
+            log::trace!("solved {delta_solved} terms");
 
             if delta_solved == 0 {
                 break;
@@ -235,7 +240,7 @@ impl<'a> Wirings<'a> {
 
             for index in 0..z_terms.len() {
                 let (z_term_index, term) = z_terms[index];
-                // eprintln!("{} {:?} {:?}", term.out, term.op, self.term_depth_re(term));
+                log::trace!("{} {:?} {:?}", term.out, term.op, self.term_depth_re(term));
 
                 // let z_depth: u64 = term.out[1..].parse().unwrap(); equal to index
                 let mut tags = Vec::new();
@@ -245,24 +250,20 @@ impl<'a> Wirings<'a> {
                 if term.op != Op::Xor && term.out != "z45" {
                     tags.push("nxor");
 
-                    // eprintln!("nxor {z_term_index} z{index}");
+                    log::trace!("nxor {z_term_index} z{index}");
                     let replacements: Vec<_> = self
                         .terms
                         .iter()
                         .enumerate()
                         .filter(|(_, term)| {
-                            // eprintln!("{:?} {:?}", self.term_depth(term), self.term_depth_re(term));
-
                             term.op == Op::Xor
                                 && !term.out.starts_with('z')
                                 && self.term_depth_re(term) == Some(proper_length)
                         })
                         .collect();
 
-                    // dbg!(replacements.len());
-
                     if replacements.len() > 1 {
-                        eprintln!("{} for {} {}", replacements.len(), proper_length, term.out);
+                        log::debug!("{} for {} {}", replacements.len(), proper_length, term.out);
                         // self.debug_dependencies(term.out);
                         // panic!();
                     }
@@ -282,11 +283,11 @@ impl<'a> Wirings<'a> {
                 //     tags.push("wrong_length");
                 // }
 
-                // eprintln!(
-                //     "{index} for {} {{{}}}",
-                //     self.term_depth(term),
-                //     tags.join(",")
-                // );
+                log::trace!(
+                    "{index} for {} {{{}}}",
+                    self.term_depth(term),
+                    tags.join(",")
+                );
 
                 // assert_eq!(term.op, Op::Xor, "z-terms are always xors");
             }
@@ -294,10 +295,11 @@ impl<'a> Wirings<'a> {
             if let Some((i, j)) = swap {
                 swaps.push((i, j));
 
-                // eprintln!(
-                //     "swapping {i} and {j}; {} and {}",
-                //     self.terms[i].out, self.terms[j].out
-                // );
+                log::debug!(
+                    "swapping {i} and {j}; {} and {}",
+                    self.terms[i].out,
+                    self.terms[j].out
+                );
 
                 let stomp = self.terms[i].out;
 
@@ -318,8 +320,7 @@ impl<'a> Wirings<'a> {
                     .collect::<Vec<_>>();
 
                 if !fucked_terms.is_empty() {
-                    // eprintln!("fucked");
-                    // eprintln!("{:?}", fucked_terms);
+                    log::debug!("fucked: {:?}", fucked_terms);
                 }
                 break;
             }
@@ -336,9 +337,9 @@ impl<'a> Wirings<'a> {
         let real_sum = self.add_seeds();
 
         self.solve();
-        eprintln!("{swaps:?}");
-        eprintln!("{zs_to_swap:?}");
-        dbg!(self.sum_bits('z'));
+        log::debug!("{swaps:?}");
+        log::debug!("{zs_to_swap:?}");
+        log::debug!("z sums to {}", self.sum_bits('z'));
 
         self.unsolve();
 
@@ -354,7 +355,7 @@ impl<'a> Wirings<'a> {
                 //     continue;
                 // }
 
-                // eprintln!("{i} {j}");
+                log::trace!("{i} {j}");
                 self.terms = original_terms.clone();
                 self.remaining_terms = original_terms.clone();
                 self.wires.retain(|k, _| k.starts_with(['x', 'y']));
@@ -375,7 +376,7 @@ impl<'a> Wirings<'a> {
                 let at_least_there_was_an_attempt_sum = self.sum_bits('z');
 
                 if real_sum == at_least_there_was_an_attempt_sum {
-                    eprintln!("real or fake? {i} {j}");
+                    log::debug!("real or fake? {i} {j}");
                     panic!();
                 }
 
@@ -432,7 +433,7 @@ impl<'a> Wirings<'a> {
         levels
     }
 
-    fn z_terms(&self) -> Vec<(usize, &Term)> {
+    fn z_terms(&self) -> Vec<(usize, &Term<'_>)> {
         let mut z_terms: Vec<_> = self
             .terms
             .iter()
@@ -557,16 +558,16 @@ impl<'a> Wirings<'a> {
             let bad_terms = validate_term_levels(index, &term_levels);
 
             if !bad_terms.is_empty() {
-                // eprintln!(
-                //     "{:indent$}bad {} {} {} {}",
-                //     "",
-                //     term.out,
-                //     bad_terms.len(),
-                //     index,
-                //     min_bad
-                // );
-
-                // eprintln!("{min_bad} {index}");
+                log::trace!(
+                    "{:indent$}bad {} {} {} {}",
+                    "",
+                    term.out,
+                    bad_terms.len(),
+                    index,
+                    min_bad
+                );
+
+                log::trace!("{min_bad} {index}");
                 if index > min_bad {
                     bad = Some((index, bad_terms));
                     break;
@@ -585,17 +586,17 @@ impl<'a> Wirings<'a> {
 
             let bad_terms: Vec<_> = bad_terms.iter().map(|term| (*term).clone()).collect();
 
-            // dbg!(&bad_terms);
+            log::trace!("bad_terms = {:?}", &bad_terms);
 
-            // eprintln!(
-            //     "{:indent$}must swap from {min_bad} one of: {bad_terms:?}",
-            //     ""
-            // );
+            log::trace!(
+                "{:indent$}must swap from {min_bad} one of: {bad_terms:?}",
+                ""
+            );
 
             for i in 0..bad_terms.len() {
                 let term = bad_terms[i].clone();
 
-                // eprintln!("{:?}", term);
+                log::trace!("{:?}", term);
 
                 let term_index = self
                     .terms
@@ -628,15 +629,15 @@ impl<'a> Wirings<'a> {
 
                     let valid_term_counts = term_count == desired_term_count(min_bad);
 
-                    eprintln!("{} {} {}", min_bad, term_count, desired_term_count(min_bad));
+                    log::debug!("{} {} {}", min_bad, term_count, desired_term_count(min_bad));
 
-                    // eprintln!("{}", term_levels.len());
+                    log::trace!("{}", term_levels.len());
 
                     if !term_levels.is_empty() && valid_term_counts {
                         let valid = validate_term_levels(i, &term_levels);
 
                         if valid.is_empty() {
-                            eprintln!(
+                            log::debug!(
                                 "{:indent$}swap {term_index} with {i}; {} swaps; next scan {}",
                                 "",
                                 swaps.len(),
@@ -656,7 +657,7 @@ impl<'a> Wirings<'a> {
                 }
             }
 
-            eprintln!("exited from {} bad terms?", bad_terms.len());
+            log::debug!("exited from {} bad terms?", bad_terms.len());
         } else {
             return Some(swaps.to_vec());
         }
@@ -686,7 +687,7 @@ impl<'a> Wirings<'a> {
 
         for index in 0..z_terms.len() {
             let (z_term_index, term) = z_terms[index];
-            // eprintln!("{} {:?} {:?}", term.out, term.op, self.term_depth_re(term));
+            log::trace!("{} {:?} {:?}", term.out, term.op, self.term_depth_re(term));
 
             // let z_depth: u64 = term.out[1..].parse().unwrap(); equal to index
             let mut tags = Vec::new();
@@ -696,13 +697,13 @@ impl<'a> Wirings<'a> {
             if term.op != Op::Xor && term.out != "z45" {
                 tags.push("nxor");
 
-                // eprintln!("nxor {z_term_index} z{index}");
+                log::trace!("nxor {z_term_index} z{index}");
                 let replacements: Vec<_> = self
                     .terms
                     .iter()
                     .enumerate()
                     .filter(|(_, term)| {
-                        // eprintln!("{:?} {:?}", self.term_depth(term), self.term_depth_re(term));
+                        log::trace!("{:?} {:?}", self.term_depth(term), self.term_depth_re(term));
 
                         term.op == Op::Xor
                             && !term.out.starts_with('z')
@@ -710,10 +711,10 @@ impl<'a> Wirings<'a> {
                     })
                     .collect();
 
-                // dbg!(replacements.len());
+                log::trace!("replacements.len() = {:?}", replacements.len());
 
                 if replacements.len() > 1 {
-                    eprintln!("{} for {} {}", replacements.len(), proper_length, term.out);
+                    log::debug!("{} for {} {}", replacements.len(), proper_length, term.out);
                     // self.debug_dependencies(term.out);
                     // panic!();
                 }
@@ -733,20 +734,21 @@ impl<'a> Wirings<'a> {
             //     tags.push("wrong_length");
             // }
 
-            // eprintln!(
-            //     "{index} for {} {{{}}}",
-            //     self.term_depth(term),
-            //     tags.join(",")
-            // );
+            log::trace!(
+                "{index} for {} {{{}}}",
+                self.term_depth(term),
+                tags.join(",")
+            );
 
             // assert_eq!(term.op, Op::Xor, "z-terms are always xors");
         }
 
         if let Some((i, j)) = swap {
-            // eprintln!(
-            //     "swapping {i} and {j}; {} and {}",
-            //     self.terms[i].out, self.terms[j].out
-            // );
+            log::trace!(
+                "swapping {i} and {j}; {} and {}",
+                self.terms[i].out,
+                self.terms[j].out
+            );
 
             self.swap_term_outputs(i, j);
 
@@ -796,10 +798,10 @@ impl<'a> Wirings<'a> {
 
         self.solve();
         let tried_sum = self.sum_bits('z');
-        eprintln!("{swaps:?}");
-        eprintln!("{zs_to_swap:?}");
-        dbg!(real_sum);
-        dbg!(tried_sum);
+        log::debug!("{swaps:?}");
+        log::debug!("{zs_to_swap:?}");
+        log::debug!("real_sum = {:?}", real_sum);
+        log::debug!("tried_sum = {:?}", tried_sum);
 
         self.unsolve();
 
@@ -883,11 +885,11 @@ impl<'a> Wirings<'a> {
                         let at_least_there_was_an_attempt_sum = self.sum_bits('z');
 
                         if real_sum == at_least_there_was_an_attempt_sum {
-                            eprintln!("real or fake? {i} {j} {:?}", &swappy);
+                            log::debug!("real or fake? {i} {j} {:?}", &swappy);
 
                             for (from, to) in &swappy {
-                                eprintln!("{}: {}", from, self.terms[*from].out);
-                                eprintln!("{}: {}", to, self.terms[*to].out);
+                                log::debug!("{}: {}", from, self.terms[*from].out);
+                                log::debug!("{}: {}", to, self.terms[*to].out);
                             }
                             panic!();
                         }
@@ -907,7 +909,7 @@ impl<'a> Wirings<'a> {
 
         swappy.push(self.one_z_swap().unwrap());
 
-        dbg!(&swappy);
+        log::debug!("swappy = {:?}", &swappy);
 
         let original_terms = self.terms.clone();
 
@@ -941,14 +943,16 @@ impl<'a> Wirings<'a> {
                     let at_least_there_was_an_attempt_sum = self.sum_bits('z');
 
                     if real_sum == at_least_there_was_an_attempt_sum {
-                        eprintln!(
+                        log::debug!(
                             "real or fake? {i} {j} {} {} {:?}",
-                            self.terms[i].out, self.terms[j].out, &swappy
+                            self.terms[i].out,
+                            self.terms[j].out,
+                            &swappy
                         );
 
                         for (from, to) in &swappy {
-                            eprintln!("{}: {}", from, self.terms[*from].out);
-                            eprintln!("{}: {}", to, self.terms[*to].out);
+                            log::debug!("{}: {}", from, self.terms[*from].out);
+                            log::debug!("{}: {}", to, self.terms[*to].out);
                         }
                         // panic!();
                     }
@@ -960,48 +964,80 @@ impl<'a> Wirings<'a> {
             }
         }
 
-        dbg!(swappy);
+        log::debug!("swappy = {:?}", swappy);
 
-        dbg!(swaps);
+        log::debug!("swaps = {:?}", swaps);
     }
 
     fn debug_dependencies(&self, fort: &'a str) {
-        eprintln!("----");
+        log::debug!("----");
+
+        if let Some(tree) = self.dependencies(fort) {
+            log::debug!("{tree}");
+        }
+    }
 
-        let for_term = self.terms.iter().find(|t| t.out == fort).unwrap();
+    /// Every term that goes into the wire `fort`, as an indented tree
+    fn dependencies(&self, fort: &str) -> Option<String> {
+        let for_term = self.terms.iter().find(|t| t.out == fort)?;
 
-        fn print(depth: usize, wirings: &Wirings, term: &Term) {
+        fn print(depth: usize, wirings: &Wirings, term: &Term, tree: &mut String) {
             let mut indent = depth * 2;
 
-            eprintln!(
+            writeln!(
+                tree,
                 "{:indent$}{} {} {} = {}",
                 "",
                 term.a,
                 term.op.to_str(),
                 term.b,
                 term.out
-            );
+            )
+            .unwrap();
 
             indent += 2;
 
             if let Some(seed) = wirings.wires.get(term.a) {
-                // eprintln!("{:indent$}{} = {seed}", "", term.a);
+                writeln!(tree, "{:indent$}{} = {seed}", "", term.a).unwrap();
             } else {
                 let term_a = wirings.terms.iter().find(|t| t.out == term.a).unwrap();
 
-                print(depth + 1, wirings, term_a);
+                print(depth + 1, wirings, term_a, tree);
             }
 
             if let Some(seed) = wirings.wires.get(term.b) {
-                // eprintln!("{:indent$}{} = {seed}", "", term.b);
+                writeln!(tree, "{:indent$}{} = {seed}", "", term.b).unwrap();
             } else {
                 let term_b = wirings.terms.iter().find(|t| t.out == term.b).unwrap();
 
-                print(depth + 1, wirings, term_b);
+                print(depth + 1, wirings, term_b, tree);
             }
         }
 
-        print(0, self, for_term);
+        let mut tree = String::new();
+
+        print(0, self, for_term, &mut tree);
+
+        Some(tree.trim_end().to_string())
+    }
+
+    /// Which pairs of gates need their outputs swapped back to make this an
+    /// adder, by where they are in the list
+    fn find_swaps(&self) -> Result<Vec<(usize, usize)>, String> {
+        Adder::new(self)?.fix()
+    }
+
+    /// Every wire that got swapped, in order, the way the site wants them
+    fn swapped_wires(&self) -> Result<String, String> {
+        let mut wires: Vec<_> = self
+            .find_swaps()?
+            .into_iter()
+            .flat_map(|(i, j)| [self.terms[i].out, self.terms[j].out])
+            .collect();
+
+        wires.sort();
+
+        Ok(wires.join(","))
     }
 
     fn reenact_divine_punishment(&self, swaps: &str) -> u64 {
@@ -1032,47 +1068,639 @@ impl<'a> Wirings<'a> {
     }
 }
 
-pub fn part1() {
-    let mut wirings = Wirings::from_str(INPUT);
+/// A circuit that's meant to be a ripple-carry adder, looked up by what goes
+/// into each gate. Swapping outputs around never changes what goes in, so
+/// the lookup holds up while we try swaps.
+struct Adder<'w> {
+    bits: usize,
+    /// Gates by op and inputs, the smaller input first
+    by_inputs: HashMap<(Op, &'w str, &'w str), usize>,
+    /// Every gate's inputs, the other way around
+    inputs: Vec<(Op, &'w str, &'w str)>,
+    /// The gates every wire goes into
+    consumers: HashMap<&'w str, Vec<usize>>,
+    /// What every gate currently outputs to
+    outs: Vec<&'w str>,
+    /// Which gate outputs to every wire
+    producers: HashMap<&'w str, usize>,
+    /// Gates that got swapped already, no gate gets swapped twice
+    swapped: Vec<bool>,
+    /// `z00` to `zNN`, so checking them doesn't mean formatting them
+    zs: Vec<String>,
+    xs: Vec<String>,
+    ys: Vec<String>,
+}
+
+/// How far an adder checks out before something's off, and what it'd take
+/// to sort it out
+struct Broken<'w> {
+    /// The bit that's off, and how far into checking it things got, so
+    /// fixing one thing of several in a bit still counts for something
+    at: (usize, u8),
+    /// The gates that output the wrong thing
+    gates: Vec<usize>,
+    /// Everything they might be meant to output instead
+    wants: Vec<Vec<&'w str>>,
+}
+
+impl<'w> Adder<'w> {
+    fn new(wirings: &Wirings<'w>) -> Result<Self, String> {
+        let count = |prefix: char| wirings.wires.keys().filter(|w| w.starts_with(prefix)).count();
+        let bits = count('x');
+
+        if bits == 0 || bits != count('y') {
+            return Err(format!(
+                "an adder wants as many x as y wires, not {bits} and {}",
+                count('y')
+            ));
+        }
+
+        let inputs: Vec<_> = wirings
+            .terms
+            .iter()
+            .map(|term| (term.op, term.a.min(term.b), term.a.max(term.b)))
+            .collect();
+        let by_inputs = inputs.iter().enumerate().map(|(i, key)| (*key, i)).collect();
+        let mut consumers: HashMap<_, Vec<_>> = HashMap::new();
+
+        for (i, &(_, a, b)) in inputs.iter().enumerate() {
+            consumers.entry(a).or_default().push(i);
+            consumers.entry(b).or_default().push(i);
+        }
+        let outs: Vec<_> = wirings.terms.iter().map(|term| term.out).collect();
+        let producers = outs.iter().enumerate().map(|(i, out)| (*out, i)).collect();
+        let names = |prefix: char, count: usize| {
+            (0..count).map(|bit| format!("{prefix}{bit:02}")).collect()
+        };
+
+        Ok(Self {
+            bits,
+            by_inputs,
+            inputs,
+            consumers,
+            swapped: vec![false; outs.len()],
+            outs,
+            producers,
+            zs: names('z', bits + 1),
+            xs: names('x', bits),
+            ys: names('y', bits),
+        })
+    }
+
+    fn gate(&self, a: &str, op: Op, b: &str) -> Option<usize> {
+        self.by_inputs.get(&(op, a.min(b), a.max(b))).copied()
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.outs.swap(i, j);
+        self.producers.insert(self.outs[i], i);
+        self.producers.insert(self.outs[j], j);
+    }
+
+    /// Outputting to `name`, if anything does
+    fn output(&self, name: &str) -> Vec<Vec<&'w str>> {
+        self.producers
+            .get_key_value(name)
+            .map(|(wire, _)| vec![*wire])
+            .into_iter()
+            .collect()
+    }
+
+    /// Both ways round of every pair of wires that go into a gate for each
+    /// of `ops`, other than x and y, since that's everything the gates of a
+    /// bit could be meant to output
+    fn pairs(&self, ops: &[Op]) -> Vec<Vec<&'w str>> {
+        self.inputs
+            .iter()
+            .filter(|&&(op, a, b)| {
+                op == ops[0]
+                    && !a.starts_with(['x', 'y'])
+                    && ops[1..].iter().all(|&op| self.gate(a, op, b).is_some())
+            })
+            .flat_map(|&(_, a, b)| [vec![a, b], vec![b, a]])
+            .collect()
+    }
 
-    wirings.solve();
+    /// Walks up from bit 0 checking every gate is where it should be, which
+    /// for bit `i` past the first is
+    ///
+    /// ```text
+    /// xi XOR yi -> s      s XOR c -> zi      b OR d -> carry
+    /// xi AND yi -> b      s AND c -> d
+    /// ```
+    ///
+    /// where `c` is the carry out of the bit before, and the top carry is
+    /// the top `z`.
+    fn check(&self) -> Result<(), Broken<'w>> {
+        let xy = |bit: usize, op| self.gate(&self.xs[bit], op, &self.ys[bit]);
+        let broken = |bit, step, gates, wants| Broken {
+            at: (bit, step),
+            gates,
+            wants,
+        };
+        let (Some(sum), Some(mut carry)) = (xy(0, Op::Xor), xy(0, Op::And)) else {
+            return Err(broken(0, 0, vec![], vec![]));
+        };
+
+        if self.outs[sum] != self.zs[0] {
+            return Err(broken(0, 1, vec![sum], self.output(&self.zs[0])));
+        }
+
+        for bit in 1..self.bits {
+            // Nothing to be done about gates that aren't there
+            let (Some(sum), Some(both)) = (xy(bit, Op::Xor), xy(bit, Op::And)) else {
+                return Err(broken(bit, 0, vec![], vec![]));
+            };
+            let (s, c) = (self.outs[sum], self.outs[carry]);
+            let (Some(z), Some(carried)) = (self.gate(s, Op::Xor, c), self.gate(s, Op::And, c))
+            else {
+                return Err(broken(
+                    bit,
+                    1,
+                    vec![sum, carry],
+                    self.pairs(&[Op::Xor, Op::And]),
+                ));
+            };
+
+            if self.outs[z] != self.zs[bit] {
+                return Err(broken(bit, 2, vec![z], self.output(&self.zs[bit])));
+            }
+
+            let Some(or) = self.gate(self.outs[both], Op::Or, self.outs[carried]) else {
+                return Err(broken(bit, 3, vec![both, carried], self.pairs(&[Op::Or])));
+            };
+
+            carry = or;
+        }
+
+        // Only the top carry gets to be a z, and it has to be the top one
+        if self.outs[carry] != self.zs[self.bits] {
+            let top = self.output(&self.zs[self.bits]);
+
+            return Err(broken(self.bits, 0, vec![carry], top));
+        }
+
+        Ok(())
+    }
+
+    /// How much of every bit looks right on its own, going by the wires
+    /// between its gates rather than the carry coming in. Fixing one bit can
+    /// often go more than one way, and the wrong ones tend to leave some
+    /// other bit looking worse off.
+    fn health(&self) -> usize {
+        (1..self.bits).map(|bit| self.bit_health(bit)).sum()
+    }
+
+    /// [`Adder::health`] for one bit, out of five
+    fn bit_health(&self, bit: usize) -> usize {
+        let xy = |bit: usize, op| self.gate(&self.xs[bit], op, &self.ys[bit]);
+        let (Some(sum), Some(both)) = (xy(bit, Op::Xor), xy(bit, Op::And)) else {
+            return 0;
+        };
+        let s = self.outs[sum];
+        let z = self
+            .consumers
+            .get(s)
+            .and_then(|gates| gates.iter().copied().find(|&gate| self.inputs[gate].0 == Op::Xor));
+        let Some(z) = z else {
+            return 0;
+        };
+        let (_, a, b) = self.inputs[z];
+        let c = if a == s { b } else { a };
+        // The carry coming in comes out of an OR, or the first AND for bit 1
+        let carry_in = match self.producers.get(c) {
+            Some(&gate) if bit == 1 => Some(gate) == xy(0, Op::And),
+            Some(&gate) => self.inputs[gate].0 == Op::Or,
+            None => false,
+        };
+        let Some(carried) = self.gate(s, Op::And, c) else {
+            return 1 + usize::from(carry_in);
+        };
+        let or = self.gate(self.outs[both], Op::Or, self.outs[carried]);
+        // And the carry going out goes into the next bit's XOR, or is the top z
+        let carry_out = or.is_some_and(|or| {
+            let out = self.outs[or];
+
+            if bit + 1 == self.bits {
+                out == self.zs[self.bits]
+            } else {
+                self.consumers
+                    .get(out)
+                    .is_some_and(|gates| gates.iter().any(|&gate| self.inputs[gate].0 == Op::Xor))
+            }
+        });
+
+        1 + usize::from(carry_in)
+            + usize::from(self.outs[z] == self.zs[bit])
+            + usize::from(or.is_some())
+            + usize::from(carry_out)
+    }
+
+    /// Swaps that make the whole thing check out, a bit at a time: fixing
+    /// whatever bit's broken first with about the fewest swaps that get the
+    /// check past it, going with whichever leaves things healthiest. That's
+    /// nearly always right, and when it isn't some bit further up can't be
+    /// fixed at all, so it backs up and tries the next best fix, but only so
+    /// many times before giving up.
+    fn fix(&mut self) -> Result<Vec<(usize, usize)>, String> {
+        const TRIES: usize = 1000;
+
+        let mut budget = TRIES;
+
+        if let Some(swaps) = self.fix_from(&mut budget) {
+            return Ok(swaps);
+        }
+
+        let bit = self.check().err().map_or(0, |broken| broken.at.0);
+
+        if budget == 0 {
+            Err(format!(
+                "gave up after trying {TRIES} fixes, it's too far gone to tell"
+            ))
+        } else {
+            Err(format!(
+                "doesn't look like an adder, bit {bit} is beyond saving"
+            ))
+        }
+    }
+
+    /// [`Adder::fix`] from whatever bit's broken first, spending some of
+    /// `budget` on every fix it tries
+    fn fix_from(&mut self, budget: &mut usize) -> Option<Vec<(usize, usize)>> {
+        let Err(broken) = self.check() else {
+            return Some(Vec::new());
+        };
+        let bit = broken.at.0;
+        let mut broken = Some(broken);
+        let mut fewest = None;
+
+        // Fixing up a bit's gates and the carry going into the next one
+        // can't take more than this
+        for limit in 1..=6 {
+            let broken = broken.take().unwrap_or_else(|| self.check().unwrap_err());
+            let mut fixes = self.fixes_within(bit, broken, limit);
+
+            if fixes.is_empty() {
+                continue;
+            }
+
+            let fewest = *fewest.get_or_insert(limit);
+
+            // One more swap than it takes sometimes goes a lot further, so
+            // those get a look in too
+            if limit == fewest && limit < 6 {
+                continue;
+            }
+
+            fixes.retain(|(_, fix)| fix.len() == limit || limit == fewest + 1);
+            fixes.sort_by_key(|(health, fix)| (Reverse(*health), fix.len()));
+
+            for (_, fix) in fixes {
+                if *budget == 0 {
+                    return None;
+                }
+
+                *budget -= 1;
+
+                for &(i, j) in &fix {
+                    self.swap(i, j);
+                    self.swapped[i] = true;
+                    self.swapped[j] = true;
+                }
+
+                let rest = self.fix_from(budget);
+
+                self.unwire(&fix);
+
+                if let Some(rest) = rest {
+                    return Some([fix, rest].concat());
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Every way of rewiring with up to `limit` swaps that gets the check
+    /// past `bit`, along with how healthy that leaves things
+    fn fixes_within(
+        &mut self,
+        bit: usize,
+        broken: Broken<'w>,
+        limit: usize,
+    ) -> Vec<(usize, Vec<(usize, usize)>)> {
+        let mut fixes = Vec::new();
+
+        for want in &broken.wants {
+            let Some(swaps) = self.rewire(&broken.gates, want) else {
+                continue;
+            };
+
+            if swaps.len() > limit {
+                self.unwire(&swaps);
+
+                continue;
+            }
+
+            match self.check() {
+                Ok(()) => fixes.push((usize::MAX, swaps.clone())),
+                // The carry out of a bit only gets checked by using it in
+                // the next one
+                Err(next) if next.at > (bit + 1, 1) => fixes.push((self.health(), swaps.clone())),
+                Err(next) if next.at > broken.at => {
+                    for (health, rest) in self.fixes_within(bit, next, limit - swaps.len()) {
+                        fixes.push((health, [swaps.clone(), rest].concat()));
+                    }
+                }
+                Err(_) => {}
+            }
 
-    dbg!(wirings.sum_bits('z'));
+            self.unwire(&swaps);
+        }
+
+        fixes
+    }
+
+    /// Swaps `gates` around so they output `wires`, if that can be done
+    /// without swapping anything twice
+    fn rewire(&mut self, gates: &[usize], wires: &[&str]) -> Option<Vec<(usize, usize)>> {
+        let mut swaps = Vec::new();
+
+        for (&gate, &wire) in gates.iter().zip(wires) {
+            if self.outs[gate] == wire {
+                continue;
+            }
+
+            match self.producers.get(wire) {
+                Some(&other) if !self.swapped[gate] && !self.swapped[other] => {
+                    self.swap(gate, other);
+                    self.swapped[gate] = true;
+                    self.swapped[other] = true;
+                    swaps.push((gate, other));
+                }
+                _ => {
+                    self.unwire(&swaps);
+
+                    return None;
+                }
+            }
+        }
+
+        Some(swaps)
+    }
+
+    /// Undoes [`Adder::rewire`]
+    fn unwire(&mut self, swaps: &[(usize, usize)]) {
+        for &(i, j) in swaps.iter().rev() {
+            self.swap(i, j);
+            self.swapped[i] = false;
+            self.swapped[j] = false;
+        }
+    }
+}
+
+/// A ripple-carry adder for `bits` bit numbers, with random x and y, gates in
+/// a random order, and `swaps` pairs of gate outputs swapped around. Also
+/// says which gates got swapped, by where they are in the list.
+fn generate_adder(
+    rng: &mut Rng,
+    bits: usize,
+    swaps: usize,
+) -> Result<(String, Vec<(usize, usize)>), String> {
+    let bits = bits.clamp(2, 99);
+    let mut taken = HashSet::new();
+    let mut name = |rng: &mut Rng| loop {
+        // Anything but x, y and z up front
+        let first = (b'a' + rng.below(23) as u8) as char;
+        let rest: String = (0..2).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+        let name = format!("{first}{rest}");
+
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut gates = Vec::new();
+    let mut gate = |rng: &mut Rng, a: &str, op: &str, b: &str, out: &str| {
+        let (a, b) = if rng.chance(0.5) { (a, b) } else { (b, a) };
+
+        gates.push(format!("{a} {op} {b} -> {out}"));
+    };
+
+    let mut carry = name(rng);
+
+    gate(rng, "x00", "XOR", "y00", "z00");
+    gate(rng, "x00", "AND", "y00", &carry);
+
+    for bit in 1..bits {
+        let (x, y, z) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("z{bit:02}"),
+        );
+        let (sum, both, carried) = (name(rng), name(rng), name(rng));
+        let next = if bit == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            name(rng)
+        };
+
+        gate(rng, &x, "XOR", &y, &sum);
+        gate(rng, &x, "AND", &y, &both);
+        gate(rng, &sum, "XOR", &carry, &z);
+        gate(rng, &sum, "AND", &carry, &carried);
+        gate(rng, &both, "OR", &carried, &next);
+
+        carry = next;
+    }
+
+    rng.shuffle(&mut gates);
+
+    let too_many = || format!("can't fit {swaps} swaps into {bits} bits");
+
+    if swaps * 2 > gates.len() {
+        return Err(too_many());
+    }
+
+    let outputs: Vec<String> = gates
+        .iter()
+        .map(|gate| gate.rsplit_once(" -> ").unwrap().1.to_string())
+        .collect();
+    let mut indices: Vec<usize> = (0..gates.len()).collect();
+
+    // Swapping two wires going into the same gate changes nothing, so
+    // there'd be no telling it happened
+    let together = |i: usize, j: usize| {
+        gates.iter().any(|gate| {
+            let wires: Vec<_> = gate.split(' ').collect();
+
+            [wires[0], wires[2]] == [&outputs[i], &outputs[j]]
+                || [wires[0], wires[2]] == [&outputs[j], &outputs[i]]
+        })
+    };
+
+    // Some swaps make loops, which isn't much of an adder. With enough swaps
+    // that's most of them, so only try so many times.
+    let tries = (0..1000).find_map(|_| {
+        rng.shuffle(&mut indices);
+
+        let pairs: Vec<_> = indices[..swaps * 2]
+            .chunks(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect();
+
+        if pairs.iter().any(|&(i, j)| together(i, j)) {
+            return None;
+        }
+
+        let mut swapped = gates.clone();
+
+        for &(i, j) in &pairs {
+            let input = |k: usize| gates[k].rsplit_once(" -> ").unwrap().0.to_string();
+
+            swapped[i] = format!("{} -> {}", input(i), outputs[j]);
+            swapped[j] = format!("{} -> {}", input(j), outputs[i]);
+        }
+
+        settles(&swapped).then_some((swapped, pairs))
+    });
+    let (swapped, pairs) = tries.ok_or_else(too_many)?;
+
+    let mut input = String::new();
+
+    for wire in ['x', 'y'] {
+        for bit in 0..bits {
+            writeln!(input, "{wire}{bit:02}: {}", rng.below(2)).unwrap();
+        }
+    }
+
+    input.push('\n');
+
+    for gate in swapped {
+        writeln!(input, "{gate}").unwrap();
+    }
+
+    Ok((input, pairs))
+}
+
+/// Whether every gate gets to go off, which they won't if some are waiting
+/// on each other
+fn settles(gates: &[String]) -> bool {
+    let mut known = HashSet::new();
+    let mut waiting: Vec<Vec<&str>> = gates
+        .iter()
+        .map(|gate| gate.split(' ').step_by(2).collect())
+        .collect();
+
+    loop {
+        let before = waiting.len();
+
+        waiting.retain(|gate| {
+            let [a, b, out] = gate[..] else {
+                unreachable!("gates look like a OP b -> out");
+            };
+            let ready = [a, b]
+                .iter()
+                .all(|wire| wire.starts_with(['x', 'y']) || known.contains(wire));
+
+            if ready {
+                known.insert(out);
+            }
+
+            !ready
+        });
+
+        if waiting.is_empty() {
+            return true;
+        }
+
+        if waiting.len() == before {
+            return false;
+        }
+    }
 }
 
-pub fn part2() {
-    let mut wirings = Wirings::from_str(INPUT);
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Wirings<'a>;
+
+    fn parse(input: &str) -> Result<Wirings<'_>, ParseError> {
+        Wirings::from_str(input)
+    }
 
-    dbg!(wirings.add_seeds());
+    fn part1(wirings: &Wirings) -> Answer {
+        let mut wirings = wirings.clone();
 
-    let a = wirings.reenact_divine_punishment(
-        "195: npf
-14: z13
-106: gws
-158: nnt
-207: cph
-212: z19
-101: hgj
-90: z33",
-    );
+        wirings.solve();
 
-    dbg!(a);
+        wirings.sum_bits('z').into()
+    }
 
-    // wirings.shortcut_v2();
+    fn part2(wirings: &Wirings) -> Answer {
+        wirings
+            .swapped_wires()
+            .unwrap_or_else(|reason| aoc::unsolvable(reason))
+            .into()
+    }
 
-    // wirings.fix_fuck(&[], 0);
+    fn commands() -> Vec<Command<Self>> {
+        vec![
+            Command::new(
+                "deps",
+                "deps WIRE: what goes into a wire",
+                |wirings, args| {
+                    let wire: String = command::arg(args, 0, "a wire")?;
+
+                    wirings
+                        .dependencies(&wire)
+                        .ok_or(format!("no term outputs to {wire}"))
+                },
+            ),
+            Command::new(
+                "swaps",
+                "swaps: which gates need their outputs swapped back, and onto what",
+                |wirings, _| {
+                    let swaps = wirings.find_swaps()?;
+
+                    Ok(swaps
+                        .iter()
+                        .map(|&(i, j)| {
+                            format!("{} <-> {}", wirings.terms[i].out, wirings.terms[j].out)
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n"))
+                },
+            ),
+            Command::new(
+                "fixed",
+                "fixed: x + y, and z with the swaps in",
+                |wirings, _| {
+                    let mut fixed = wirings.clone();
+
+                    for (i, j) in wirings.find_swaps()? {
+                        fixed.swap_term_outputs(i, j);
+                    }
 
-    // dbg!(wirings.add_seeds());
+                    fixed.solve();
 
-    // wirings.find_first_fucked_bit();
+                    Ok(format!(
+                        "{} and {}",
+                        wirings.add_seeds(),
+                        fixed.sum_bits('z')
+                    ))
+                },
+            ),
+        ]
+    }
 
-    // wirings.debug_dependencies("z00");
-    // wirings.debug_dependencies("z01");
-    // wirings.debug_dependencies("z02");
-    // wirings.debug_dependencies("z03");
-    // wirings.debug_dependencies("z04");
-    // wirings.debug_dependencies("z05");
-    // wirings.debug_dependencies("z06");
+    /// `size` bits, with as many swaps as the first argument says, or the
+    /// puzzle's 4
+    fn generate(rng: &mut Rng, size: usize, args: &[&str]) -> Result<String, String> {
+        let swaps = command::arg_or(args, 0, "a swap count", 4)?;
+
+        generate_adder(rng, size, swaps).map(|(input, _)| input)
+    }
 }
 
 #[cfg(test)]
@@ -1093,7 +1721,8 @@ y02: 0
 x00 AND y00 -> z00
 x01 XOR y01 -> z01
 x02 OR y02 -> z02",
-        );
+        )
+        .unwrap();
 
         wirings.debug_dependencies("z00");
         wirings.debug_dependencies("z01");
@@ -1155,7 +1784,8 @@ y03 OR x01 -> nrd
 hwm AND bqk -> z03
 tgd XOR rvg -> z12
 tnw OR pbm -> gnj",
-        );
+        )
+        .unwrap();
 
         wirings.solve();
         dbg!(&wirings.terms);
@@ -1214,7 +1844,8 @@ y03 OR x01 -> nrd
 hwm AND bqk -> z03
 tgd XOR rvg -> z12
 tnw OR pbm -> gnj",
-        );
+        )
+        .unwrap();
 
         wirings.debug_dependencies("z00");
         wirings.debug_dependencies("z01");
@@ -1275,7 +1906,8 @@ y03 OR x01 -> nrd
 hwm AND bqk -> z03
 tgd XOR rvg -> z12
 tnw OR pbm -> gnj",
-        );
+        )
+        .unwrap();
 
         wirings.debug_dependencies("z00");
         wirings.debug_dependencies("z01");
@@ -1284,4 +1916,102 @@ tnw OR pbm -> gnj",
         wirings.debug_dependencies("z04");
         wirings.debug_dependencies("z05");
     }
+
+    #[test]
+    fn generated_adders() {
+        for seed in 0..10 {
+            let mut rng = Rng::new(seed);
+            let (input, _) = generate_adder(&mut rng, 12, 0).unwrap();
+            let mut wirings = Wirings::from_str(&input).unwrap();
+
+            wirings.solve();
+
+            assert_eq!(wirings.sum_bits('z'), wirings.add_seeds(), "seed {seed}");
+
+            let (input, pairs) = generate_adder(&mut rng, 10, 1 + seed as usize % 4).unwrap();
+            let wirings = Wirings::from_str(&input).unwrap();
+            let swaps: String = pairs.iter().map(|(i, j)| format!("{i}: x\n{j}: x\n")).collect();
+
+            assert_eq!(
+                wirings.reenact_divine_punishment(&swaps),
+                wirings.add_seeds(),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn too_many_swaps() {
+        let mut rng = Rng::new(1);
+
+        assert_eq!(
+            Day24::generate(&mut rng, 2, &["4"]),
+            Err("can't fit 4 swaps into 2 bits".to_string())
+        );
+        assert!(Day24::generate(&mut rng, 45, &["112"]).is_err());
+        assert!(Day24::generate(&mut rng, 45, &["12"]).is_ok());
+    }
+
+    #[test]
+    fn finds_swaps() {
+        for seed in 0..20 {
+            let (input, _) =
+                generate_adder(&mut Rng::new(seed), 16, 1 + seed as usize % 4).unwrap();
+            let wirings = Wirings::from_str(&input).unwrap();
+            let mut fixed = wirings.clone();
+
+            for (i, j) in wirings.find_swaps().unwrap() {
+                fixed.swap_term_outputs(i, j);
+            }
+
+            fixed.solve();
+
+            assert_eq!(fixed.sum_bits('z'), wirings.add_seeds(), "seed {seed}");
+        }
+
+        // Something the size of the real thing, with a lot more wrong with it
+        for seed in 0..10 {
+            let (input, _) = generate_adder(&mut Rng::new(seed), 45, 12).unwrap();
+            let wirings = Wirings::from_str(&input).unwrap();
+            let swaps = wirings.find_swaps().unwrap();
+            let mut fixed = wirings.clone();
+
+            assert!(swaps.len() <= 12, "seed {seed}");
+
+            for (i, j) in swaps {
+                fixed.swap_term_outputs(i, j);
+            }
+
+            fixed.solve();
+
+            assert_eq!(fixed.sum_bits('z'), wirings.add_seeds(), "seed {seed}");
+        }
+
+        let not_an_adder = Wirings::from_str("x00: 1\ny00: 0\n\nx00 OR y00 -> z00\n").unwrap();
+
+        assert!(not_an_adder.find_swaps().is_err());
+        assert!(Wirings::from_str("x00: 1\n\n").unwrap().find_swaps().is_err());
+    }
+
+    #[test]
+    fn generated_adders_round_trip() {
+        for seed in 0..40 {
+            let swaps = 1 + seed as usize % 5;
+            let mut rng = Rng::new(seed);
+            let (input, pairs) = generate_adder(&mut rng, 8 + seed as usize % 37, swaps).unwrap();
+            let wirings = Wirings::from_str(&input).unwrap();
+            let mut swapped: Vec<_> = pairs
+                .iter()
+                .flat_map(|&(i, j)| [wirings.terms[i].out, wirings.terms[j].out])
+                .collect();
+
+            swapped.sort();
+
+            assert_eq!(
+                Day24::part2(&wirings),
+                swapped.join(",").into(),
+                "seed {seed}"
+            );
+        }
+    }
 }
@@ -0,0 +1,245 @@
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+
+#[derive(Debug)]
+pub struct EndMeAlready {
+    key_holes: Vec<MarketingPins>,
+    keys: Vec<MarketingPins>,
+}
+
+#[derive(Debug)]
+struct MarketingPins {
+    ty: PinsType,
+    heights: Vec<usize>,
+}
+
+#[derive(Debug)]
+enum PinsType {
+    KeyHole,
+    Key,
+}
+
+impl MarketingPins {
+    fn from_lines<'a>(
+        input: &str,
+        lines: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Option<Self>, ParseError> {
+        let mut loins: Vec<Vec<bool>> = vec![];
+        for line in lines {
+            if line.is_empty() {
+                break;
+            }
+
+            let row = parse::row(input, line, |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })?;
+
+            if loins.first().is_some_and(|first| first.len() != row.len()) {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "pins don't line up with the row above",
+                ));
+            }
+
+            loins.push(row);
+        }
+
+        if loins.is_empty() {
+            return Ok(None);
+        }
+
+        let ty = if loins[0].iter().all(|&pin| pin) {
+            PinsType::KeyHole
+        } else {
+            PinsType::Key
+        };
+        let mut heights = vec![0; loins[0].len()];
+
+        for row in loins.iter() {
+            for (i, &pin) in row.iter().enumerate() {
+                if pin {
+                    heights[i] += 1;
+                }
+            }
+        }
+
+        // We don't count the bottom/top row
+        for h in heights.iter_mut() {
+            *h -= 1;
+        }
+
+        Ok(Some(Self { ty, heights }))
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.heights
+            .iter()
+            .zip(other.heights.iter())
+            .any(|(pin_depth, hole_depth)| pin_depth + hole_depth > 5)
+    }
+}
+
+impl EndMeAlready {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut nico_and_the_liners = parse::lines(input);
+        let mut key_holes = vec![];
+        let mut keys = vec![];
+
+        while let Some(pins) = MarketingPins::from_lines(input, &mut nico_and_the_liners)? {
+            if matches!(pins.ty, PinsType::Key) {
+                keys.push(pins);
+            } else {
+                key_holes.push(pins);
+            }
+        }
+
+        Ok(Self { key_holes, keys })
+    }
+
+    fn try_all_to_see_what_fucking_fits(&self) -> u64 {
+        let mut overlaps = 0;
+        for key in self.keys.iter() {
+            for lock in self.key_holes.iter() {
+                if !key.overlaps(lock) {
+                    overlaps += 1;
+                }
+            }
+        }
+
+        overlaps
+    }
+}
+
+/// `size` locks and keys with five pins each, drawn seven rows tall like
+/// the real ones. Comes with how many lock and key pairs fit together.
+fn generate_schematics(rng: &mut Rng, size: usize) -> (String, u64) {
+    let mut locks = vec![];
+    let mut keys = vec![];
+    let mut schematics = vec![];
+
+    for _ in 0..size.max(1) {
+        let heights: Vec<usize> = (0..5).map(|_| rng.below(6)).collect();
+        let lock = rng.chance(0.5);
+        let rows: Vec<String> = (0..7)
+            .map(|row| {
+                // Locks hang down from the top row, keys stick up from the bottom
+                let depth = if lock { row } else { 6 - row };
+
+                heights
+                    .iter()
+                    .map(|&height| if depth <= height { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        schematics.push(rows.join("\n") + "\n");
+
+        if lock {
+            locks.push(heights);
+        } else {
+            keys.push(heights);
+        }
+    }
+
+    let fits = locks
+        .iter()
+        .flat_map(|lock| keys.iter().map(move |key| (lock, key)))
+        .filter(|(lock, key)| lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5))
+        .count();
+
+    (schematics.join("\n"), fits as u64)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = EndMeAlready;
+
+    // How did we get here?
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<EndMeAlready, ParseError> {
+        EndMeAlready::from_str(input)
+    }
+
+    fn part1(end: &EndMeAlready) -> Answer {
+        end.try_all_to_see_what_fucking_fits().into()
+    }
+
+    /// Never gets asked, the 50th star is free for having the other 49
+    fn part2(_end: &EndMeAlready) -> Answer {
+        aoc::unsolvable("there's no part 2 on day 25")
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate_schematics(rng, size).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let end = EndMeAlready::from_str(
+            "#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####",
+        )
+        .unwrap();
+
+        dbg!(&end);
+        dbg!(end.try_all_to_see_what_fucking_fits());
+    }
+
+    #[test]
+    fn generated_schematics() {
+        for seed in 0..20 {
+            let (input, fits) = generate_schematics(&mut Rng::new(seed), 100);
+            let end = EndMeAlready::from_str(&input).unwrap();
+
+            assert_eq!(input, generate_schematics(&mut Rng::new(seed), 100).0);
+            assert_eq!(end.keys.len() + end.key_holes.len(), 100);
+            assert_eq!(end.try_all_to_see_what_fucking_fits(), fits, "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+use regex::{Captures, Regex};
+
+pub struct Instructions {
+    instructions: Vec<Instruction>,
+}
+
+enum Instruction {
+    Do,
+    Dont,
+    Mul { a: i64, b: i64 },
+}
+
+impl Instruction {
+    fn from_captures(input: &str, c: &Captures) -> Result<Self, ParseError> {
+        // Gross. But I can't use captures for the prefix without messing up the parser
+        let f = c.get(0).unwrap();
+        if f.as_str().starts_with("don't") {
+            return Ok(Self::Dont);
+        } else if f.as_str().starts_with("do") {
+            return Ok(Self::Do);
+        }
+
+        let a = parse::number(input, c.get(1).unwrap().as_str())?;
+        let b = parse::number(input, c.get(2).unwrap().as_str())?;
+
+        Ok(Self::Mul { a, b })
+    }
+}
+
+impl Instructions {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let regex = Regex::new(r"do\(\)|don't\(\)|mul\((\d+),(\d+)\)").unwrap();
+
+        let instructions = regex
+            .captures_iter(input)
+            .map(|r| Instruction::from_captures(input, &r))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { instructions })
+    }
+
+    fn mul_add(&self) -> i64 {
+        self.instructions
+            .iter()
+            .filter_map(|ins| {
+                if let Instruction::Mul { a, b } = ins {
+                    Some(a * b)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    fn mul_add_enabled(&self) -> i64 {
+        let mut enabled = true;
+        self.instructions
+            .iter()
+            .filter_map(|ins| match ins {
+                Instruction::Do => {
+                    enabled = true;
+                    None
+                }
+                Instruction::Dont => {
+                    enabled = false;
+                    None
+                }
+                Instruction::Mul { a, b } if enabled => Some(a * b),
+                _ => None,
+            })
+            .sum()
+    }
+}
+
+/// Bits of corrupted memory that look like instructions but aren't. None of
+/// them start or end in a way that could make a real one with their neighbours.
+const JUNK: [&str; 14] = [
+    "mul[3,7]",
+    "mul(32,64]",
+    "mul ( 2 , 4 )",
+    "mul(6,9!",
+    "mul(4*",
+    "do_not_",
+    "undo",
+    "don't",
+    "what()",
+    "select(",
+    "?",
+    "!",
+    "@",
+    "^",
+];
+
+/// About `size` instructions' worth of corrupted memory, along with what
+/// parts 1 and 2 should add up to
+fn generate_memory(rng: &mut Rng, size: usize) -> (String, [i64; 2]) {
+    let mut memory = String::new();
+    let mut sums = [0, 0];
+    let mut enabled = true;
+
+    for _ in 0..size.max(1) {
+        match rng.below(10) {
+            0 => {
+                memory.push_str("do()");
+                enabled = true;
+            }
+            1 => {
+                memory.push_str("don't()");
+                enabled = false;
+            }
+            2..=5 => {
+                let a = rng.range(1..1000) as i64;
+                let b = rng.range(1..1000) as i64;
+
+                memory.push_str(&format!("mul({a},{b})"));
+                sums[0] += a * b;
+
+                if enabled {
+                    sums[1] += a * b;
+                }
+            }
+            _ => memory.push_str(rng.pick::<&str>(&JUNK)),
+        }
+
+        if rng.chance(0.02) {
+            memory.push('\n');
+        }
+    }
+
+    memory.push('\n');
+
+    (memory, sums)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = Instructions;
+
+    fn parse(input: &str) -> Result<Instructions, ParseError> {
+        Instructions::from_str(input)
+    }
+
+    fn part1(inst: &Instructions) -> Answer {
+        inst.mul_add().into()
+    }
+
+    fn part2(inst: &Instructions) -> Answer {
+        inst.mul_add_enabled().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate_memory(rng, size).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ejemplo_uno() {
+        let inst = Instructions::from_str(
+            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
+        )
+        .unwrap();
+
+        assert_eq!(inst.mul_add(), 161);
+    }
+
+    #[test]
+    fn ejemplo_dos() {
+        let inst = Instructions::from_str(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        )
+        .unwrap();
+
+        assert_eq!(inst.mul_add_enabled(), 48);
+    }
+
+    #[test]
+    fn generated_memory() {
+        for seed in 0..20 {
+            let (input, [part1, part2]) = generate_memory(&mut Rng::new(seed), 500);
+            let inst = Instructions::from_str(&input).unwrap();
+
+            assert_eq!(input, generate_memory(&mut Rng::new(seed), 500).0);
+            assert_eq!(inst.mul_add(), part1, "seed {seed}");
+            assert_eq!(inst.mul_add_enabled(), part2, "seed {seed}");
+        }
+    }
+}
@@ -1,10 +1,7 @@
-use std::time::Instant;
-
+use aoc::{parse, Answer, ParseError, Rng, Solution, Variant};
 use regex::Regex;
 
-const INPUT: &str = include_str!("../../../inputs/day4.txt");
-
-struct Lettermap {
+pub struct Lettermap {
     chars: Vec<Vec<char>>,
     bookkeeping_as: Vec<(usize, usize)>,
 }
@@ -12,8 +9,15 @@ struct Lettermap {
 type ShutupClippy<'a> = &'a [&'a [fn(char) -> bool]];
 
 impl Lettermap {
-    fn from_str(s: &str) -> Self {
-        let chars: Vec<Vec<_>> = s.lines().map(|line| line.chars().collect()).collect();
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let chars = parse::grid(input, Some)?;
+
+        if chars.is_empty() {
+            return Err(ParseError::eof(
+                input,
+                "expected some letters to look through",
+            ));
+        }
         let bookkeeping_as = chars
             .iter()
             .enumerate()
@@ -24,10 +28,10 @@ impl Lettermap {
             })
             .collect();
 
-        Self {
+        Ok(Self {
             chars,
             bookkeeping_as,
-        }
+        })
     }
 
     fn col_count(&self) -> usize {
@@ -37,8 +41,8 @@ impl Lettermap {
     fn regex_2d(&self, regex: Regex, width: usize, height: usize) -> usize {
         let mut count = 0;
 
-        for row in 0..(self.chars.len() - height + 1) {
-            for col in 0..(self.col_count() - width + 1) {
+        for row in 0..(self.chars.len() + 1).saturating_sub(height) {
+            for col in 0..(self.col_count() + 1).saturating_sub(width) {
                 let iter = (0..height).flat_map(|i| {
                     (0..width)
                         .map(move |j| self.chars[row + i][col + j])
@@ -64,8 +68,8 @@ impl Lettermap {
 
         let mut scratch = "_".repeat((width + 1) * height);
 
-        for row in 0..(self.chars.len() - height + 1) {
-            for col in 0..(self.col_count() - width + 1) {
+        for row in 0..(self.chars.len() + 1).saturating_sub(height) {
+            for col in 0..(self.col_count() + 1).saturating_sub(width) {
                 unsafe {
                     let slice = scratch.as_bytes_mut();
                     let mut offset = 0;
@@ -96,8 +100,8 @@ impl Lettermap {
         let col_count = pattern[0].len();
         let mut count = 0;
 
-        for row in 0..(self.chars.len() - row_count + 1) {
-            for col in 0..(self.col_count() - col_count + 1) {
+        for row in 0..(self.chars.len() + 1).saturating_sub(row_count) {
+            for col in 0..(self.col_count() + 1).saturating_sub(col_count) {
                 let matched = pattern.iter().enumerate().all(|(i, pats)| {
                     pats.iter()
                         .enumerate()
@@ -113,6 +117,7 @@ impl Lettermap {
         count
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn count_xmas(&self) -> usize {
         let mut count = 0;
 
@@ -198,8 +203,8 @@ impl Lettermap {
         let mut count = 0;
 
         let c = &self.chars;
-        for row in 0..(self.chars.len() - 2) {
-            for col in 0..(self.col_count() - 2) {
+        for row in 0..self.chars.len().saturating_sub(2) {
+            for col in 0..self.col_count().saturating_sub(2) {
                 if c[row + 1][col + 1] == 'A'
                     && ((c[row][col] == 'M' && c[row + 2][col + 2] == 'S')
                         || (c[row][col] == 'S' && c[row + 2][col + 2] == 'M'))
@@ -284,39 +289,59 @@ impl Lettermap {
     }
 }
 
-pub fn part1() {
-    let map = Lettermap::from_str(INPUT);
+/// A word search `size` letters square, all X, M, A and S
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
 
-    dbg!(map.count_xmas());
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size).map(|_| *rng.pick(&['X', 'M', 'A', 'S'])).collect();
+
+            row + "\n"
+        })
+        .collect()
 }
 
-fn time<T>(label: &str, f: impl FnOnce() -> T) -> T {
-    let start = Instant::now();
-    let v = f();
-    let elapsed = start.elapsed();
+pub struct Day4;
 
-    eprintln!(
-        "{label} took {time}ms to execute (that's microseconds)",
-        time = elapsed.as_micros()
-    );
+impl Solution for Day4 {
+    type Parsed<'a> = Lettermap;
 
-    v
-}
+    fn parse(input: &str) -> Result<Lettermap, ParseError> {
+        Lettermap::from_str(input)
+    }
 
-pub fn part2() {
-    let map = Lettermap::from_str(INPUT);
+    fn part1(map: &Lettermap) -> Answer {
+        map.count_xmas().into()
+    }
 
-    dbg!(time("x-mas", || map.count_x_mas()));
-    dbg!(time("cooler x-mas", || map.count_x_mas_cooler()));
-    dbg!(time("regex x-mas", || map.count_x_mas_regex()));
-    dbg!(time("unsafe regex x-mas", || map.count_x_mas_regex_unsafe()));
-    dbg!(time("puxscan x-mas", || map.count_x_mas_puxscan()));
+    fn part2(map: &Lettermap) -> Answer {
+        map.count_x_mas().into()
+    }
+
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant::new(2, "cooler", |map| map.count_x_mas_cooler().into()),
+            Variant::new(2, "regex", |map| map.count_x_mas_regex().into()),
+            Variant::new(2, "unsafe_regex", |map| {
+                map.count_x_mas_regex_unsafe().into()
+            }),
+            Variant::new(2, "puxscan", |map| map.count_x_mas_puxscan().into()),
+        ]
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// The bigger example, checked in so these run on a fresh checkout too
+    const EXAMPLE: &str = include_str!("../examples/1.txt");
+
     #[test]
     fn ejemplo_uno() {
         let map = Lettermap::from_str(
@@ -330,7 +355,8 @@ S.S.S.S.SS
 .A.A.A.A.A
 ..M.M.M.MM
 .X.X.XMASX",
-        );
+        )
+        .unwrap();
 
         assert_eq!(map.count_xmas(), 18);
     }
@@ -348,36 +374,64 @@ S.S.S.S.S.
 .A.A.A.A..
 M.M.M.M.M.
 ..........",
-        );
+        )
+        .unwrap();
 
         assert_eq!(map.count_x_mas(), 9);
     }
 
     #[test]
     fn cooler() {
-        let map = Lettermap::from_str(INPUT);
+        let map = Lettermap::from_str(EXAMPLE).unwrap();
 
         assert_eq!(map.count_x_mas(), map.count_x_mas_cooler());
     }
 
     #[test]
     fn regex() {
-        let map = Lettermap::from_str(INPUT);
+        let map = Lettermap::from_str(EXAMPLE).unwrap();
 
         assert_eq!(map.count_x_mas(), map.count_x_mas_regex());
     }
 
     #[test]
     fn unsafe_regex() {
-        let map = Lettermap::from_str(INPUT);
+        let map = Lettermap::from_str(EXAMPLE).unwrap();
 
         assert_eq!(map.count_x_mas(), map.count_x_mas_regex_unsafe());
     }
 
     #[test]
     fn lots_of_puxlove() {
-        let map = Lettermap::from_str(INPUT);
+        let map = Lettermap::from_str(EXAMPLE).unwrap();
 
         assert_eq!(map.count_x_mas(), map.count_x_mas_puxscan());
     }
+
+    #[test]
+    fn empty_input() {
+        let error = Lettermap::from_str("").err().unwrap();
+
+        assert_eq!(error.message, "expected some letters to look through");
+        assert!(Lettermap::from_str("\n\n").is_err());
+    }
+
+    #[test]
+    fn generated_word_searches() {
+        for seed in 0..20 {
+            let size = 1 + seed as usize * 3;
+            let input = generate(&mut Rng::new(seed), size);
+            let map = Lettermap::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), size));
+            assert_eq!((map.col_count(), map.chars.len()), (size, size));
+
+            let x_mas = map.count_x_mas();
+
+            assert_eq!(map.count_x_mas_cooler(), x_mas, "seed {seed}");
+            assert_eq!(map.count_x_mas_regex(), x_mas, "seed {seed}");
+            assert_eq!(map.count_x_mas_regex_unsafe(), x_mas, "seed {seed}");
+            assert_eq!(map.count_x_mas_puxscan(), x_mas, "seed {seed}");
+        }
+    }
 }
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+use scan::Scanner;
+
+#[derive(Clone)]
+pub struct Update {
+    ordering_rules: OrderingRules,
+    pages: Vec<Vec<i64>>,
+}
+
+#[derive(Clone)]
+struct OrderingRules {
+    reverse: HashMap<i64, Vec<i64>>,
+}
+
+impl OrderingRules {
+    fn new(pairs: impl Iterator<Item = (i64, i64)>) -> Self {
+        let mut reverse: HashMap<i64, Vec<i64>> = HashMap::new();
+
+        for (x, y) in pairs {
+            reverse.entry(y).or_default().push(x);
+        }
+
+        Self { reverse }
+    }
+
+    fn is_ok(&self, line: &[i64]) -> bool {
+        line.iter().enumerate().all(|(index, nom)| {
+            let Some(no_no_noms) = self.reverse.get(nom) else {
+                // no requirements for the nom
+                return true;
+            };
+
+            line.iter().skip(index + 1).all(|n| !no_no_noms.contains(n))
+        })
+    }
+
+    fn fix_line(&self, line: &mut [i64]) {
+        loop {
+            let switch = line.iter().enumerate().find_map(|(index, nom)| {
+                let Some(no_no_noms) = self.reverse.get(nom) else {
+                    // no requirements for the nom
+                    return None;
+                };
+
+                line.iter()
+                    .enumerate()
+                    .skip(index + 1)
+                    .find(|(_, n)| no_no_noms.contains(n))
+                    .map(|(i, _)| (index, i))
+            });
+
+            if let Some((x, y)) = switch {
+                line[x..(y + 1)].rotate_left(1);
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl Update {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input);
+        let ordering_rules = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                scan::line(input, line, |s| {
+                    let x = s.unsigned()?;
+                    s.literal("|")?;
+
+                    Ok((x, s.unsigned()?))
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        let pages = lines
+            .map(|line| scan::line(input, line, |s| s.list(",", Scanner::unsigned)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            ordering_rules: OrderingRules::new(ordering_rules.into_iter()),
+            pages,
+        })
+    }
+
+    fn count_mids(&self) -> i64 {
+        let mut count = 0;
+
+        for line in self.pages.iter().filter(|line| self.ordering_rules.is_ok(line)) {
+            count += line[line.len() / 2];
+        }
+
+        count
+    }
+
+    fn count_fixed(&mut self) -> i64 {
+        let mut count = 0;
+
+        for line in self.pages.iter_mut().filter(|line| !self.ordering_rules.is_ok(line)) {
+            self.ordering_rules.fix_line(line);
+
+            count += line[line.len() / 2];
+        }
+
+        count
+    }
+}
+
+/// Rules putting 49 pages in some secret order, with one for every pair like
+/// the real thing, then `size` updates, about half of them in the right
+/// order. Comes with what parts 1 and 2 should add up to.
+fn generate_updates(rng: &mut Rng, size: usize) -> (String, [i64; 2]) {
+    let mut order: Vec<i64> = (10..100).collect();
+
+    rng.shuffle(&mut order);
+    order.truncate(49);
+
+    let rank: HashMap<i64, usize> = order.iter().enumerate().map(|(i, &page)| (page, i)).collect();
+    let mut rules: Vec<_> = (0..order.len())
+        .flat_map(|i| (i + 1..order.len()).map(move |j| (i, j)))
+        .map(|(i, j)| format!("{}|{}\n", order[i], order[j]))
+        .collect();
+
+    rng.shuffle(&mut rules);
+
+    let mut input = rules.concat() + "\n";
+    let mut sums = [0, 0];
+
+    for _ in 0..size.max(1) {
+        let mut line = order.clone();
+
+        rng.shuffle(&mut line);
+        line.truncate(rng.range(2..12) * 2 + 1);
+
+        if rng.chance(0.5) {
+            line.sort_by_key(|page| rank[page]);
+        }
+
+        let pages: Vec<_> = line.iter().map(i64::to_string).collect();
+
+        input.push_str(&pages.join(","));
+        input.push('\n');
+
+        if line.is_sorted_by_key(|page| rank[page]) {
+            sums[0] += line[line.len() / 2];
+        } else {
+            line.sort_by_key(|page| rank[page]);
+            sums[1] += line[line.len() / 2];
+        }
+    }
+
+    (input, sums)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = Update;
+
+    fn parse(input: &str) -> Result<Update, ParseError> {
+        Update::from_str(input)
+    }
+
+    fn part1(update: &Update) -> Answer {
+        update.count_mids().into()
+    }
+
+    fn part2(update: &Update) -> Answer {
+        let mut update = update.clone();
+
+        update.count_fixed().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate_updates(rng, size).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc::check_examples::<Day5>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn generated_updates() {
+        // Fixing lines this way is slow going, so not too many
+        for seed in 0..4 {
+            let (input, [part1, part2]) = generate_updates(&mut Rng::new(seed), 4);
+            let mut update = Update::from_str(&input).unwrap();
+
+            assert_eq!(input, generate_updates(&mut Rng::new(seed), 4).0);
+            assert_eq!(update.pages.len(), 4);
+            assert_eq!(update.count_mids(), part1, "seed {seed}");
+            assert_eq!(update.count_fixed(), part2, "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::HashSet;
+
+use aoc::{Answer, ParseError, Rng, Solution};
+use grid::{Coord, Direction, Grid};
+
+#[derive(Clone)]
+pub struct Map {
+    cells: Grid<Cell>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Cell {
+    FreeSpace,
+    Barrier,
+    Playa,
+    Walked,
+}
+
+/// A bit per direction, for remembering which ways we've walked through
+fn flag(direction: Direction) -> u8 {
+    1 << direction as u8
+}
+
+impl Cell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::FreeSpace),
+            '#' => Some(Self::Barrier),
+            '^' => Some(Self::Playa),
+            _ => None,
+        }
+    }
+}
+
+impl Map {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let cells = Grid::parse(input, Cell::from_char)?;
+
+        if cells.find(|cell| *cell == Cell::Playa).is_none() {
+            return Err(ParseError::eof(input, "the map has no ^ guard on it"));
+        }
+
+        Ok(Self { cells })
+    }
+
+    fn where_me(&self) -> Coord {
+        self.cells
+            .find(|cell| *cell == Cell::Playa)
+            .expect("Parsing makes sure there's a guard man")
+    }
+
+    fn next(&self, pos: Coord, direction: Direction) -> Option<(Coord, Direction)> {
+        let next = self.cells.step(pos, direction)?;
+
+        if self.cells[next] == Cell::Barrier {
+            Some((pos, direction.turn_right()))
+        } else {
+            Some((next, direction))
+        }
+    }
+
+    fn walk(&mut self) {
+        let mut direction = Direction::Up;
+        let mut pos = self.where_me();
+
+        while let Some((next, dir)) = self.next(pos, direction) {
+            self.cells[pos] = Cell::Walked;
+            direction = dir;
+            pos = next;
+        }
+
+        self.cells[pos] = Cell::Walked;
+    }
+
+    /// Whether the guard makes it out instead of going round in circles
+    fn escapes(&self) -> bool {
+        let mut seen = Grid::filled(self.cells.width(), self.cells.height(), 0u8);
+        let mut direction = Direction::Up;
+        let mut pos = self.where_me();
+
+        while let Some((next, dir)) = self.next(pos, direction) {
+            if seen[next] & flag(dir) != 0 {
+                return false;
+            }
+
+            seen[next] |= flag(dir);
+            direction = dir;
+            pos = next;
+        }
+
+        true
+    }
+
+    fn walked_cells(&self) -> usize {
+        self.cells.cells().iter().filter(|&c| *c == Cell::Walked).count()
+    }
+
+    fn walk_twisting(&mut self) -> usize {
+        let start_position = self.where_me();
+        let mut direction = Direction::Up;
+        let mut pos = start_position;
+        let mut positions = vec![];
+
+        while let Some((next, dir)) = self.next(pos, direction) {
+            positions.push(pos);
+
+            direction = dir;
+            pos = next;
+        }
+
+        positions.push(pos);
+
+        let mut looped = 0;
+        let mut tried = HashSet::new();
+
+        for barry in positions.into_iter() {
+            let mut local_hookup = vec![0u8; self.cells.cells().len()];
+            let mut pos = start_position;
+            let mut direction = Direction::Up;
+
+            if tried.contains(&barry) {
+                continue;
+            }
+
+            tried.insert(barry);
+
+            if barry == start_position {
+                log::trace!("can't put a barrier where the guard starts");
+                continue;
+            }
+
+            let old = self.cells[barry].clone();
+
+            if old == Cell::Barrier {
+                log::trace!("{barry:?} is already a barrier");
+                continue;
+            }
+
+            log::trace!("trying a barrier at {barry:?}");
+
+            self.cells[barry] = Cell::Barrier;
+
+            // Our first movement will be blocked by the barrier
+            // assert_eq!(
+            //     (pos, direction.turn_cockwise()),
+            //     self.next(pos, &direction).unwrap()
+            // );
+
+            // walk the walk with the new barrier and new state
+            while let Some((next, dir)) = self.next(pos, direction) {
+                let flog = flag(dir);
+                let ind = self.cells.index_of(next);
+
+                if local_hookup[ind] & flog == flog {
+                    log::debug!("found loopy banoopy at {dir:?} {next:?}, barrier at {barry:?}");
+
+                    looped += 1;
+
+                    break;
+                } else {
+                    log::trace!("move {pos:?} to {next:?}");
+                    local_hookup[ind] |= flog;
+
+                    direction = dir;
+                    pos = next;
+                }
+            }
+
+            self.cells[barry] = old;
+        }
+
+        looped
+    }
+}
+
+/// A square map `size` wide, scattered with barriers, and a guard who
+/// eventually walks off it
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    loop {
+        let mut cells: Vec<char> = (0..size * size)
+            .map(|_| if rng.chance(0.05) { '#' } else { '.' })
+            .collect();
+        let guard = rng.below(cells.len());
+
+        cells[guard] = '^';
+
+        let map: String = cells
+            .chunks(size)
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+
+        if Map::from_str(&map).is_ok_and(|parsed| parsed.escapes()) {
+            return map;
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::from_str(input)
+    }
+
+    fn part1(map: &Map) -> Answer {
+        let mut map = map.clone();
+
+        map.walk();
+
+        map.walked_cells().into()
+    }
+
+    fn part2(map: &Map) -> Answer {
+        let mut map = map.clone();
+
+        map.walk_twisting().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let mut map = Map::from_str(
+            "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...",
+        )
+        .unwrap();
+
+        assert_eq!(map.cells.width(), 10);
+        assert_eq!(map.cells.height(), 10);
+
+        map.walk();
+
+        assert_eq!(map.walked_cells(), 41);
+    }
+
+    #[test]
+    fn example_two() {
+        let mut map = Map::from_str(
+            "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...",
+        )
+        .unwrap();
+
+        assert_eq!(map.walk_twisting(), 6);
+    }
+
+    #[test]
+    fn generated_maps() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), 16);
+            let map = Map::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 16));
+            assert_eq!((map.cells.width(), map.cells.height()), (16, 16));
+
+            // Trying a barrier everywhere, the slow and obvious way
+            let start = map.where_me();
+            let loops = map
+                .cells
+                .coords()
+                .filter(|&coord| coord != start && map.cells[coord] == Cell::FreeSpace)
+                .filter(|&coord| {
+                    let mut blocked = map.clone();
+
+                    blocked.cells[coord] = Cell::Barrier;
+
+                    !blocked.escapes()
+                })
+                .count();
+
+            assert_eq!(map.clone().walk_twisting(), loops, "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn no_guard() {
+        let error = Map::from_str("..#\n...\n").err().unwrap();
+
+        assert_eq!(error.message, "the map has no ^ guard on it");
+        assert!(Map::from_str("").is_err());
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt::Write;
+
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+use scan::Scanner;
+
+pub struct Bridge {
+    equations: Vec<Equation>,
+}
+
+struct Equation {
+    ideal: i64,
+    atoms: Vec<i64>,
+}
+
+#[derive(Clone)]
+enum Operators {
+    Add,
+    Mul,
+    Concat,
+}
+
+impl Operators {
+    fn next(&self) -> Option<Operators> {
+        match self {
+            Operators::Add => Some(Operators::Mul),
+            Operators::Mul => None,
+            _ => unreachable!(),
+        }
+    }
+
+    fn next_concat(&self) -> Option<Operators> {
+        match self {
+            Operators::Add => Some(Operators::Mul),
+            Operators::Mul => Some(Operators::Concat),
+            Operators::Concat => None,
+        }
+    }
+}
+
+impl Bridge {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            equations: parse::lines(input)
+                .map(|line| Equation::from_line(input, line))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn solvable_sum(&self) -> i64 {
+        self.equations
+            .iter()
+            .filter(|e| e.is_solvable())
+            .fold(0, |sum, eq| sum + eq.ideal)
+    }
+
+    fn solvable_concat(&self) -> i64 {
+        self.equations
+            .iter()
+            .filter(|e| e.is_solvable_concat())
+            .fold(0, |sum, eq| sum + eq.ideal)
+    }
+}
+
+impl Equation {
+    fn from_line(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (ideal, atoms) = scan::key_value(input, line)?;
+        let ideal = scan::line(input, ideal, Scanner::unsigned)?;
+        let atoms = scan::line(input, atoms, |s| s.list(" ", Scanner::unsigned))?;
+
+        if atoms.len() < 2 {
+            return Err(ParseError::at(
+                input,
+                line,
+                "need two numbers to fit an operator between",
+            ));
+        }
+
+        Ok(Self { ideal, atoms })
+    }
+
+    fn try_reduce_ops(ops: &mut [Operators], concat: bool) -> bool {
+        let mut reduced = None;
+
+        for i in (0..ops.len()).rev() {
+            let next = if concat {
+                ops[i].next_concat()
+            } else {
+                ops[i].next()
+            };
+
+            if let Some(new) = next {
+                reduced = Some(i);
+                ops[i] = new;
+                break;
+            }
+        }
+
+        if let Some(start) = reduced {
+            ops.iter_mut().skip(start + 1).for_each(|op| *op = Operators::Add);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_solvable(&self) -> bool {
+        assert!(self.atoms.len() > 1);
+
+        let mut ops = vec![Operators::Add; self.atoms.len() - 1];
+
+        loop {
+            let mut start = self.atoms[0];
+            self.atoms
+                .iter()
+                .skip(1)
+                .enumerate()
+                .for_each(|(index, atom)| match ops[index] {
+                    Operators::Add => start += atom,
+                    Operators::Mul => start *= atom,
+                    _ => unreachable!(),
+                });
+
+            if start == self.ideal {
+                return true;
+            }
+
+            if !Equation::try_reduce_ops(&mut ops, false) {
+                break;
+            }
+        }
+
+        false
+    }
+
+    fn is_solvable_concat(&self) -> bool {
+        assert!(self.atoms.len() > 1);
+
+        let mut ops = vec![Operators::Add; self.atoms.len() - 1];
+
+        loop {
+            let mut scratch = String::new();
+
+            let mut start = self.atoms[0];
+            self.atoms
+                .iter()
+                .skip(1)
+                .enumerate()
+                .for_each(|(index, atom)| match ops[index] {
+                    Operators::Add => start += atom,
+                    Operators::Mul => start *= atom,
+                    Operators::Concat => {
+                        scratch.clear();
+
+                        write!(&mut scratch, "{}", start).unwrap();
+                        write!(&mut scratch, "{}", atom).unwrap();
+
+                        start = scratch.parse().unwrap();
+                    }
+                });
+
+            if start == self.ideal {
+                return true;
+            }
+
+            if !Equation::try_reduce_ops(&mut ops, true) {
+                break;
+            }
+        }
+
+        false
+    }
+}
+
+/// `size` equations of up to seven smallish numbers, worked out with a
+/// random mix of operators, some of them then knocked off a bit so they
+/// don't work out any more. Concatenating everything is the biggest any of
+/// them can get, so it all stays well inside an i64.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = String::new();
+
+    for _ in 0..size.max(1) {
+        let atoms: Vec<i64> = (0..rng.range(2..8)).map(|_| rng.range(1..100) as i64).collect();
+        let ops = if rng.chance(0.7) { 2 } else { 3 };
+        let mut ideal = atoms[0];
+
+        for &atom in &atoms[1..] {
+            ideal = match rng.below(ops) {
+                0 => ideal + atom,
+                1 => ideal * atom,
+                _ => format!("{ideal}{atom}").parse().expect("Digits don't parse man"),
+            };
+        }
+
+        if rng.chance(0.25) {
+            ideal += rng.range(1..10) as i64;
+        }
+
+        let atoms: Vec<_> = atoms.iter().map(i64::to_string).collect();
+
+        writeln!(lines, "{ideal}: {}", atoms.join(" ")).expect("Can't write to a string man");
+    }
+
+    lines
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Bridge;
+
+    fn parse(input: &str) -> Result<Bridge, ParseError> {
+        Bridge::from_str(input)
+    }
+
+    fn part1(bridge: &Bridge) -> Answer {
+        bridge.solvable_sum().into()
+    }
+
+    fn part2(bridge: &Bridge) -> Answer {
+        bridge.solvable_concat().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let bridge = Bridge::from_str(
+            "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20",
+        )
+        .unwrap();
+
+        assert_eq!(bridge.solvable_sum(), 3749);
+    }
+
+    /// Every way of putting operators in, from the left
+    fn reachable(so_far: i64, atoms: &[i64], ideal: i64, concat: bool) -> bool {
+        let Some((&atom, rest)) = atoms.split_first() else {
+            return so_far == ideal;
+        };
+
+        reachable(so_far + atom, rest, ideal, concat)
+            || reachable(so_far * atom, rest, ideal, concat)
+            || concat
+                && reachable(
+                    format!("{so_far}{atom}").parse().unwrap(),
+                    rest,
+                    ideal,
+                    concat,
+                )
+    }
+
+    #[test]
+    fn generated_equations() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), 50);
+            let bridge = Bridge::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), 50));
+            assert_eq!(bridge.equations.len(), 50);
+
+            for equation in &bridge.equations {
+                let (&first, rest) = equation.atoms.split_first().unwrap();
+
+                assert_eq!(
+                    equation.is_solvable(),
+                    reachable(first, rest, equation.ideal, false)
+                );
+                assert_eq!(
+                    equation.is_solvable_concat(),
+                    reachable(first, rest, equation.ideal, true)
+                );
+            }
+        }
+    }
+}
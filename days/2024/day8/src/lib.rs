@@ -0,0 +1,286 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{parse, Answer, ParseError, Rng, Solution};
+
+type Coord = (usize, usize);
+
+#[derive(Debug, Clone)]
+pub struct City {
+    width: usize,
+    height: usize,
+    signalis: HashMap<Coord, Antenna>,
+    resonance: HashMap<Coord, HashSet<Antenna>>,
+}
+
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+struct Antenna(u8);
+
+impl Antenna {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            // 48..=57 -> 0..=9
+            '0'..='9' => Some(Antenna(c as u8 - 48)),
+            // 65..=90 -> 10..=35
+            'A'..='Z' => Some(Antenna(c as u8 - 55)),
+            // 97..=122 -> 36..=61
+            'a'..='z' => Some(Antenna(c as u8 - 61)),
+            _ => None,
+        }
+    }
+}
+
+impl City {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut signalis = HashMap::new();
+        let resonance = HashMap::new();
+
+        // Empty spots are fine, anything else had better be an antenna
+        let rows = parse::grid(input, |c| match c {
+            '.' => Some(None),
+            c => Antenna::from_char(c).map(Some),
+        })?;
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, antenna) in row.iter().enumerate() {
+                if let Some(antenna) = antenna {
+                    signalis.insert((x, y), antenna.clone());
+                }
+            }
+        }
+
+        Ok(Self {
+            width: rows.first().map_or(0, Vec::len),
+            height: rows.len(),
+            signalis,
+            resonance,
+        })
+    }
+
+    fn reverse_map(&self) -> HashMap<Antenna, HashSet<Coord>> {
+        let mut reverse = HashMap::new();
+        for (cell, antenna) in self.signalis.iter() {
+            reverse
+                .entry(antenna.clone())
+                .or_insert_with(HashSet::new)
+                .insert(*cell);
+        }
+
+        reverse
+    }
+
+    fn fill_resonances(&mut self) {
+        let reverse = self.reverse_map();
+
+        for (antenna, cells) in reverse.into_iter() {
+            self.resonances_for(&cells).for_each(|mut resonances| {
+                // skip root for 1st in-bounds resonance
+                let resonance = resonances.nth(1);
+
+                if let Some(resonance) = resonance {
+                    self.resonance.entry(resonance).or_default().insert(antenna.clone());
+                }
+            });
+        }
+    }
+
+    fn fill_resonances_repeating(&mut self) {
+        let reverse = self.reverse_map();
+
+        for (antenna, cells) in reverse.into_iter() {
+            self.resonances_for(&cells).for_each(|resonances| {
+                resonances.for_each(|resonance| {
+                    self.resonance.entry(resonance).or_default().insert(antenna.clone());
+                });
+            });
+        }
+    }
+
+    fn resonances_for<'a>(
+        &self,
+        coords: &'a HashSet<Coord>,
+    ) -> impl Iterator<Item = impl Iterator<Item = Coord> + 'a> + 'a {
+        let width = self.width as i64;
+        let height = self.height as i64;
+
+        coords.iter().flat_map(move |cell| {
+            coords.iter().filter(move |&c| c != cell).map(move |other_cell| {
+                let icell = (cell.0 as i64, cell.1 as i64);
+                let iother_cell = (other_cell.0 as i64, other_cell.1 as i64);
+
+                let diff = (icell.0 - iother_cell.0, icell.1 - iother_cell.1);
+                let mut end = icell;
+
+                (0..).map_while(move |_| {
+                    if end.0 < 0 || end.0 >= width || end.1 < 0 || end.1 >= height {
+                        // out of bounds
+                        return None;
+                    }
+
+                    let uend = (end.0 as usize, end.1 as usize);
+                    end = (end.0 + diff.0, end.1 + diff.1);
+
+                    Some(uend)
+                })
+            })
+        })
+    }
+}
+
+/// A city `size` blocks square with a few antennas on each of a handful of
+/// frequencies
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let frequencies: Vec<char> = ('0'..='9').chain('A'..='Z').chain('a'..='z').collect();
+    let mut cells = vec!['.'; size * size];
+    let mut free: Vec<usize> = (0..cells.len()).collect();
+
+    rng.shuffle(&mut free);
+
+    for _ in 0..rng.range(1..size / 3 + 2) {
+        let frequency = *rng.pick(&frequencies);
+
+        for _ in 0..rng.range(2..5) {
+            if let Some(cell) = free.pop() {
+                cells[cell] = frequency;
+            }
+        }
+    }
+
+    cells
+        .chunks(size)
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = City;
+
+    fn parse(input: &str) -> Result<City, ParseError> {
+        City::from_str(input)
+    }
+
+    fn part1(city: &City) -> Answer {
+        let mut map = city.clone();
+        map.fill_resonances();
+
+        map.resonance.len().into()
+    }
+
+    fn part2(city: &City) -> Answer {
+        let mut map = city.clone();
+        map.fill_resonances_repeating();
+
+        map.resonance.len().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_one() {
+        let mut city = City::from_str(
+            "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............",
+        )
+        .unwrap();
+
+        city.fill_resonances();
+
+        assert_eq!(city.resonance.len(), 14);
+    }
+
+    #[test]
+    fn example_two() {
+        let mut city = City::from_str(
+            "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............",
+        )
+        .unwrap();
+
+        city.fill_resonances_repeating();
+
+        assert_eq!(city.resonance.len(), 34);
+    }
+
+    #[test]
+    fn generated_cities() {
+        for seed in 0..20 {
+            let size = 1 + seed as usize * 2;
+            let input = generate(&mut Rng::new(seed), size);
+            let city = City::from_str(&input).unwrap();
+
+            assert_eq!(input, generate(&mut Rng::new(seed), size));
+            assert_eq!((city.width, city.height), (size, size));
+
+            // Checking every spot against every pair of antennas instead
+            let pairs: Vec<_> = city
+                .signalis
+                .iter()
+                .flat_map(|(&a, antenna)| {
+                    city.signalis
+                        .iter()
+                        .filter(move |&(&b, other)| a != b && antenna == other)
+                        .map(move |(&b, _)| (a, b))
+                })
+                .collect();
+            let spots = (0..size).flat_map(|y| (0..size).map(move |x| (x as i64, y as i64)));
+            let antinodes = |repeating: bool| {
+                spots
+                    .clone()
+                    .filter(|&(x, y)| {
+                        pairs.iter().any(|&(a, b)| {
+                            let (ax, ay) = (a.0 as i64, a.1 as i64);
+                            let (dx, dy) = (ax - b.0 as i64, ay - b.1 as i64);
+                            let (ox, oy) = (x - ax, y - ay);
+
+                            if repeating {
+                                let k = if dx != 0 { ox / dx } else { oy / dy };
+
+                                k >= 0 && (ox, oy) == (k * dx, k * dy)
+                            } else {
+                                (ox, oy) == (dx, dy)
+                            }
+                        })
+                    })
+                    .count()
+            };
+
+            let mut once = city.clone();
+            let mut repeating = city.clone();
+
+            once.fill_resonances();
+            repeating.fill_resonances_repeating();
+
+            assert_eq!(once.resonance.len(), antinodes(false), "seed {seed}");
+            assert_eq!(repeating.resonance.len(), antinodes(true), "seed {seed}");
+        }
+    }
+}
@@ -1,7 +1,7 @@
-const INPUT: &str = include_str!("../../../inputs/day9.txt");
+use aoc::{Answer, ParseError, Rng, Solution};
 
-#[derive(Debug)]
-struct Disk {
+#[derive(Debug, Clone)]
+pub struct Disk {
     sectors: Vec<Sector>,
 }
 
@@ -27,15 +27,22 @@ enum Unit {
 }
 
 impl Disk {
-    fn from_str(input: &str) -> Self {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
         let mut file = true;
         let mut file_id = 0;
+        let map = input.trim_end();
 
-        Self {
-            sectors: input
-                .chars()
-                .filter_map(|c| {
-                    let n = c.to_digit(10)? as usize;
+        if map.is_empty() {
+            return Err(ParseError::eof(input, "expected a disk map"));
+        }
+
+        Ok(Self {
+            sectors: map
+                .char_indices()
+                .map(|(i, c)| {
+                    let n = c.to_digit(10).ok_or_else(|| {
+                        ParseError::at(input, &map[i..i + c.len_utf8()], "disk maps are all digits")
+                    })? as usize;
 
                     let sector = if file {
                         let file = Sector::File {
@@ -52,10 +59,10 @@ impl Disk {
 
                     file = !file;
 
-                    Some(sector)
+                    Ok(sector)
                 })
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
     }
 
     fn expand(&self) -> Vec<Unit> {
@@ -96,11 +103,11 @@ impl Disk {
                 }
             }
 
-            // eprintln!(
-            //     "{start} {end} {starts:?} {ends:?}",
-            //     starts = expanded[start],
-            //     ends = expanded[end]
-            // );
+            log::trace!(
+                "swapping {start} and {end}: {starts:?} {ends:?}",
+                starts = expanded[start],
+                ends = expanded[end]
+            );
             expanded.swap(start, end);
 
             start += 1;
@@ -166,12 +173,10 @@ impl Disk {
 
             sectors[end + 1] = Sector::Empty { size: file_size };
 
-            // eprintln!(
-            //     "{start} {end} {starts:?} {ends:?}",
-            //     starts = expanded[start],
-            //     ends = expanded[end]
-            // );
-            // expanded.swap(start, end);
+            log::trace!(
+                "moved {file:?} from {end} to {start}",
+                file = sectors[start]
+            );
 
             if end <= 1 {
                 break;
@@ -197,16 +202,46 @@ impl Disk {
     }
 }
 
-pub fn part1() {
-    let disk = Disk::from_str(INPUT);
+/// A disk map `size` digits long, starting and ending with a file, where
+/// files take up at least a block and free space might take none
+fn generate(rng: &mut Rng, size: usize) -> String {
+    // An even length would end on free space, which is pointless
+    let size = size.max(1) | 1;
+    let mut map: String = (0..size)
+        .map(|i| {
+            let least = if i % 2 == 0 { 1 } else { 0 };
 
-    dbg!(disk.move_singles_checksum());
+            char::from_digit(rng.range(least..10) as u32, 10).unwrap()
+        })
+        .collect();
+
+    map.push('\n');
+
+    map
 }
 
-pub fn part2() {
-    let mut disk = Disk::from_str(INPUT);
+pub struct Day9;
 
-    dbg!(disk.move_files_checksum());
+impl Solution for Day9 {
+    type Parsed<'a> = Disk;
+
+    fn parse(input: &str) -> Result<Disk, ParseError> {
+        Disk::from_str(input)
+    }
+
+    fn part1(disk: &Disk) -> Answer {
+        disk.move_singles_checksum().into()
+    }
+
+    fn part2(disk: &Disk) -> Answer {
+        let mut disk = disk.clone();
+
+        disk.move_files_checksum().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize, _args: &[&str]) -> Result<String, String> {
+        Ok(generate(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -215,22 +250,73 @@ mod tests {
 
     #[test]
     fn example_one() {
-        let disk = Disk::from_str("12345");
+        let disk = Disk::from_str("12345").unwrap();
 
         assert_eq!(disk.move_singles_checksum(), 60);
     }
 
     #[test]
     fn example_one_two() {
-        let disk = Disk::from_str("2333133121414131402");
+        let disk = Disk::from_str("2333133121414131402").unwrap();
 
         assert_eq!(disk.move_singles_checksum(), 1928);
     }
 
     #[test]
     fn example_two() {
-        let mut disk = Disk::from_str("2333133121414131402");
+        let mut disk = Disk::from_str("2333133121414131402").unwrap();
 
         assert_eq!(disk.move_files_checksum(), 2858);
     }
+
+    /// Moves whole files block by block, the slow and obvious way
+    fn move_files_slowly(map: &str) -> usize {
+        let mut blocks: Vec<Option<usize>> = Vec::new();
+
+        for (i, c) in map.trim_end().chars().enumerate() {
+            let id = (i % 2 == 0).then_some(i / 2);
+
+            blocks.extend(std::iter::repeat_n(id, c.to_digit(10).unwrap() as usize));
+        }
+
+        for id in (0..map.len().div_ceil(2)).rev() {
+            let Some(start) = blocks.iter().position(|&b| b == Some(id)) else {
+                continue;
+            };
+            let size = blocks.iter().filter(|&&b| b == Some(id)).count();
+            let free = (0..start).find(|&i| blocks[i..i + size].iter().all(Option::is_none));
+
+            if let Some(free) = free {
+                blocks[free..free + size].fill(Some(id));
+                blocks[start..start + size].fill(None);
+            }
+        }
+
+        blocks.iter().enumerate().map(|(i, b)| i * b.unwrap_or(0)).sum()
+    }
+
+    #[test]
+    fn generated_disks() {
+        assert_eq!(move_files_slowly("2333133121414131402"), 2858);
+
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), 101);
+            let mut disk = Disk::from_str(&input).unwrap();
+
+            assert_eq!(input.trim_end().len(), 101);
+            assert_eq!(disk.sectors.len(), 101);
+            assert_eq!(
+                disk.move_files_checksum(),
+                move_files_slowly(&input),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        let error = Disk::from_str("\n").err().unwrap();
+
+        assert_eq!(error.message, "expected a disk map");
+    }
 }
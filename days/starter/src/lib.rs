@@ -1,23 +1,39 @@
-const INPUT: &str = include_str!("../../../inputs/dayN.txt");
+use aoc::{parse, Answer, ParseError, Solution};
 
-struct Thing {}
+pub struct Thing {}
 
 impl Thing {
-    fn from_str(input: &str) -> Self {
-        Self {}
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        for line in parse::lines(input) {}
+
+        Ok(Self {})
     }
 }
 
-pub fn part1() {}
+pub struct DayN;
+
+impl Solution for DayN {
+    type Parsed<'a> = Thing;
+
+    fn parse(input: &str) -> Result<Thing, ParseError> {
+        Thing::from_str(input)
+    }
+
+    fn part1(thing: &Thing) -> Answer {
+        todo!()
+    }
 
-pub fn part2() {}
+    fn part2(thing: &Thing) -> Answer {
+        todo!()
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn example_one() {
-        let thing = Thing::from_str("");
+    fn examples() {
+        aoc::check_examples::<DayN>(env!("CARGO_MANIFEST_DIR"));
     }
 }
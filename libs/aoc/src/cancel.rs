@@ -0,0 +1,89 @@
+//! Cooperative cancellation, so the runner can give up on a part that's
+//! taking forever and the part can notice and stop burning the CPU
+
+use std::{
+    cell::RefCell,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Flipped by the runner when a part runs out of time
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Runs `f` with this as the token [`cancelled`] looks at on this thread
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        // Puts the old token back even if `f` unwinds
+        struct Restore(Option<CancelToken>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.set(self.0.take());
+            }
+        }
+
+        let _restore = Restore(CURRENT.replace(Some(self.clone())));
+
+        f()
+    }
+}
+
+/// What a cancelled part unwinds with, so the runner can tell it apart from
+/// a part that blew up
+#[derive(Debug)]
+pub struct Cancelled;
+
+/// Whether whoever is running this part would like it to stop
+pub fn cancelled() -> bool {
+    CURRENT.with_borrow(|token| token.as_ref().is_some_and(CancelToken::is_cancelled))
+}
+
+/// For long loops: bails out of the part if it's been cancelled. Doesn't go
+/// through the panic hook, so nothing gets printed on the way out.
+pub fn check_cancelled() {
+    if cancelled() {
+        std::panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancels_only_in_scope() {
+        let token = CancelToken::new();
+
+        token.scope(|| {
+            assert!(!cancelled());
+            token.cancel();
+            assert!(cancelled());
+        });
+
+        assert!(!cancelled());
+
+        let unwound = std::panic::catch_unwind(|| token.scope(check_cancelled)).unwrap_err();
+
+        assert!(unwound.is::<Cancelled>());
+        assert!(!cancelled());
+    }
+}
@@ -0,0 +1,74 @@
+//! Named commands a day offers in the REPL, for poking at its parsed input
+//! without writing throwaway tests
+
+use std::str::FromStr;
+
+use crate::Solution;
+
+/// Runs a command with its arguments against the parsed input, returning
+/// whatever should get printed
+pub type Execute<S> =
+    for<'a, 'b> fn(&'b <S as Solution>::Parsed<'a>, &[&str]) -> Result<String, String>;
+
+/// Runs any command by name, as handed out by [`crate::Solver::explore`]
+pub type Session<'a> = dyn FnMut(&str, &[&str]) -> Result<String, String> + 'a;
+
+/// One of a day's REPL commands, by name
+pub struct Command<S: Solution> {
+    pub name: &'static str,
+    /// Shown by `help`, so it should say what the arguments are
+    pub help: &'static str,
+    pub execute: Execute<S>,
+}
+
+impl<S: Solution> Command<S> {
+    pub fn new(name: &'static str, help: &'static str, execute: Execute<S>) -> Self {
+        Self {
+            name,
+            help,
+            execute,
+        }
+    }
+}
+
+/// Reads the `index`th argument, called `what` when complaining about it
+pub fn arg<T: FromStr>(args: &[&str], index: usize, what: &str) -> Result<T, String> {
+    let arg = args.get(index).ok_or_else(|| format!("missing {what}"))?;
+
+    arg.parse().map_err(|_| format!("{arg:?} doesn't work as {what}"))
+}
+
+/// Like [`arg`], but falls back to `default` when the argument is left out
+pub fn arg_or<T: FromStr>(
+    args: &[&str],
+    index: usize,
+    what: &str,
+    default: T,
+) -> Result<T, String> {
+    if index < args.len() {
+        arg(args, index, what)
+    } else {
+        Ok(default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_arguments() {
+        let args = ["25", "x"];
+
+        assert_eq!(arg::<u32>(&args, 0, "a count"), Ok(25));
+        assert_eq!(
+            arg::<u32>(&args, 1, "a count"),
+            Err("\"x\" doesn't work as a count".to_string())
+        );
+        assert_eq!(
+            arg::<u32>(&args, 2, "a count"),
+            Err("missing a count".to_string())
+        );
+        assert_eq!(arg_or(&args, 2, "a count", 7u32), Ok(7));
+    }
+}
@@ -0,0 +1,477 @@
+//! Shared glue between the runner and every `days/YYYY/dayN` crate
+
+pub mod cancel;
+pub mod command;
+pub mod memory;
+pub mod parse;
+pub mod random;
+
+pub use cancel::{cancelled, check_cancelled, CancelToken, Cancelled};
+pub use command::Command;
+pub use memory::Memory;
+pub use parse::ParseError;
+pub use random::Rng;
+
+use std::{
+    fmt::Display,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// What a part spits out, in whichever shape the puzzle asks for
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// Rendered as `x,y`, the way the site wants coordinates
+    Coord(i64, i64),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Coord(x, y) => write!(f, "{x},{y}"),
+        }
+    }
+}
+
+macro_rules! int_answers {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(n: $ty) -> Self {
+                    Answer::Int(n.try_into().expect("answer doesn't fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+int_answers!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        Answer::Coord(x as i64, y as i64)
+    }
+}
+
+/// What a part unwinds with when the input parsed fine but isn't one it can
+/// answer, so the runner can say why instead of reporting a crash
+#[derive(Debug)]
+pub struct Unsolvable(pub String);
+
+/// Bails out of the part, with `reason` for whoever's running it. Like
+/// [`check_cancelled`], it doesn't go through the panic hook.
+pub fn unsolvable(reason: impl Into<String>) -> ! {
+    std::panic::resume_unwind(Box::new(Unsolvable(reason.into())))
+}
+
+/// A day's puzzle, split into parsing and the two parts
+///
+/// Parsing happens once and both parts get to look at the result, so parts
+/// that need to mutate the parsed state should clone what they need.
+pub trait Solution {
+    /// Parsed puzzle state; may borrow from the input text. It has to be
+    /// shareable across threads, since the runner solves days in parallel.
+    type Parsed<'a>: Send + Sync;
+
+    /// How many parts the day has, which is two for everyone except day 25
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer;
+
+    /// Only ever called when [`Solution::PARTS`] says there's a part two
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer;
+
+    /// Other ways of solving the parts, kept around to benchmark against the main ones
+    fn variants() -> Vec<Variant<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// Things the REPL can do with the parsed input besides solving the parts
+    fn commands() -> Vec<Command<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// Makes up a valid input, with `size` meaning whatever makes sense for
+    /// the day and `args` for any other knobs it has, read like a command's.
+    /// Days that can't do that yet say so.
+    fn generate(_rng: &mut Rng, _size: usize, _args: &[&str]) -> Result<String, String> {
+        Err("doesn't know how to make up inputs".to_string())
+    }
+}
+
+/// Solves a part out of the parsed input
+pub type Solve<S> = for<'a, 'b> fn(&'b <S as Solution>::Parsed<'a>) -> Answer;
+
+/// An alternative implementation of one part, by name
+pub struct Variant<S: Solution> {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: Solve<S>,
+}
+
+impl<S: Solution> Variant<S> {
+    pub fn new(part: u8, name: &'static str, solve: Solve<S>) -> Self {
+        Self { part, name, solve }
+    }
+}
+
+/// What the main solution of each part is called when listed alongside its variants
+pub const MAIN_VARIANT: &str = "main";
+
+fn main_solve<S: Solution>(part: u8) -> Option<Solve<S>> {
+    match part {
+        1 => Some(S::part1),
+        2 if S::PARTS >= 2 => Some(S::part2),
+        _ => None,
+    }
+}
+
+/// The main solve or the named variant of `part`, if there's such a thing
+fn find_solve<S: Solution>(part: u8, variant: &str) -> Option<Solve<S>> {
+    if variant == MAIN_VARIANT {
+        main_solve::<S>(part)
+    } else if part == 0 || part > S::PARTS {
+        None
+    } else {
+        S::variants()
+            .into_iter()
+            .find(|v| v.part == part && v.name == variant)
+            .map(|v| v.solve)
+    }
+}
+
+/// One part's answer, along with how long it took to get there
+pub struct Run {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+    /// What parsing and solving allocated, when [`memory`] is counting
+    pub memory: Option<Memory>,
+}
+
+/// Object-safe view of a [`Solution`], so the runner can keep every day in one list
+pub trait Solver: Sync {
+    fn parts(&self) -> u8;
+
+    fn run(&self, input: &str, part: u8) -> Result<Run, ParseError>;
+
+    /// Every way of solving `part`, starting with [`MAIN_VARIANT`]
+    fn variants(&self, part: u8) -> Vec<&'static str>;
+
+    /// Parses `input` once, then hands `bench` something that solves `part`
+    /// with the named variant every time it's called
+    fn prepare(
+        &self,
+        input: &str,
+        part: u8,
+        variant: &str,
+        bench: &mut dyn FnMut(&mut dyn FnMut() -> Answer),
+    ) -> Result<(), ParseError>;
+
+    /// A made-up input, the same one every time for the same seed and `args`
+    fn generate(&self, seed: u64, size: usize, args: &[&str]) -> Result<String, String>;
+
+    /// Names of the day's REPL commands, along with their help
+    fn commands(&self) -> Vec<(&'static str, &'static str)>;
+
+    /// Parses `input` once, then hands `session` something that runs commands
+    /// against it by name: the day's own, plus `partN [variant]`
+    fn explore(
+        &self,
+        input: &str,
+        session: &mut dyn FnMut(&mut command::Session),
+    ) -> Result<(), ParseError>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn run(&self, input: &str, part: u8) -> Result<Run, ParseError> {
+        let (timed, memory) = memory::measure(|| {
+            let start = Instant::now();
+            let parsed = S::parse(input)?;
+            let parse = start.elapsed();
+
+            let start = Instant::now();
+            let answer = match main_solve::<S>(part) {
+                Some(solve) => solve(&parsed),
+                None => unsolvable(format!("there's no part {part}, only {}", S::PARTS)),
+            };
+            let solve = start.elapsed();
+
+            Ok((answer, parse, solve))
+        });
+        let (answer, parse, solve) = timed?;
+
+        Ok(Run {
+            answer,
+            parse,
+            solve,
+            memory,
+        })
+    }
+
+    fn variants(&self, part: u8) -> Vec<&'static str> {
+        let others = S::variants().into_iter().filter(|variant| variant.part == part);
+
+        std::iter::once(MAIN_VARIANT)
+            .chain(others.map(|variant| variant.name))
+            .collect()
+    }
+
+    fn prepare(
+        &self,
+        input: &str,
+        part: u8,
+        variant: &str,
+        bench: &mut dyn FnMut(&mut dyn FnMut() -> Answer),
+    ) -> Result<(), ParseError> {
+        let solve = find_solve::<S>(part, variant)
+            .unwrap_or_else(|| panic!("No variant {variant} for part {part}"));
+
+        let parsed = S::parse(input)?;
+
+        bench(&mut || solve(&parsed));
+
+        Ok(())
+    }
+
+    fn generate(&self, seed: u64, size: usize, args: &[&str]) -> Result<String, String> {
+        S::generate(&mut Rng::new(seed), size, args)
+    }
+
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        S::commands()
+            .into_iter()
+            .map(|command| (command.name, command.help))
+            .collect()
+    }
+
+    fn explore(
+        &self,
+        input: &str,
+        session: &mut dyn FnMut(&mut command::Session),
+    ) -> Result<(), ParseError> {
+        let commands = S::commands();
+        let parsed = S::parse(input)?;
+
+        session(&mut |name, args| {
+            if let Some(part) = name.strip_prefix("part").and_then(|n| n.parse().ok()) {
+                let variant = args.first().copied().unwrap_or(MAIN_VARIANT);
+                let solve = find_solve::<S>(part, variant)
+                    .ok_or_else(|| format!("no {variant} for part {part}"))?;
+
+                return Ok(solve(&parsed).to_string());
+            }
+
+            let command = commands
+                .iter()
+                .find(|command| command.name == name)
+                .ok_or_else(|| format!("no command called {name}, try help"))?;
+
+            (command.execute)(&parsed, args)
+        });
+
+        Ok(())
+    }
+}
+
+/// Checks a day against the examples saved under `dir/examples`. Each
+/// `N.answer` holds what part N should say about `N.txt`, or about `1.txt`
+/// when part N didn't come with an example of its own.
+pub fn check_examples<S: Solution>(dir: &str) {
+    let examples = Path::new(dir).join("examples");
+    let read = |name: String| std::fs::read_to_string(examples.join(name));
+    let mut checked = 0;
+
+    for part in 1..=S::PARTS {
+        let Ok(expected) = read(format!("{part}.answer")) else {
+            continue;
+        };
+        let input = read(format!("{part}.txt"))
+            .or_else(|_| read("1.txt".to_string()))
+            .unwrap_or_else(|_| panic!("No example input for part {part} man"));
+
+        let parsed = S::parse(&input)
+            .unwrap_or_else(|error| panic!("{}", error.diagnostic(&input, "the example")));
+        let answer = main_solve::<S>(part).expect("We only go up to PARTS man")(&parsed);
+
+        assert_eq!(
+            answer.to_string(),
+            expected.trim(),
+            "part {part} of the examples"
+        );
+        checked += 1;
+    }
+
+    assert!(
+        checked > 0,
+        "No examples in {}, fetch the puzzle to get some",
+        examples.display()
+    );
+}
+
+/// A registered day, as listed by the runner
+pub struct Day {
+    pub year: u32,
+    pub number: u32,
+    pub solver: &'static dyn Solver,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("day{}", self.number)
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = u8> {
+        1..=self.solver.parts()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_render_like_the_site_wants() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("4,6,3,5").to_string(), "4,6,3,5");
+        assert_eq!(Answer::from((6usize, 1usize)).to_string(), "6,1");
+    }
+
+    struct Summer;
+
+    impl Solution for Summer {
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+            parse::lines(input).map(|n| parse::number(input, n)).collect()
+        }
+
+        fn part1(numbers: &Vec<i64>) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+
+        fn part2(numbers: &Vec<i64>) -> Answer {
+            numbers.iter().product::<i64>().into()
+        }
+
+        fn variants() -> Vec<Variant<Self>> {
+            vec![Variant::new(1, "rev", |numbers| {
+                numbers.iter().rev().sum::<i64>().into()
+            })]
+        }
+
+        fn commands() -> Vec<Command<Self>> {
+            vec![Command::new(
+                "first",
+                "first N: the first N numbers",
+                |numbers, args| {
+                    let count = command::arg(args, 0, "a count")?;
+
+                    Ok(format!("{:?}", &numbers[..count]))
+                },
+            )]
+        }
+    }
+
+    #[test]
+    fn explores_parsed_input() {
+        let mut said = Vec::new();
+
+        Summer
+            .explore("2\n3\n4", &mut |run| {
+                for line in [
+                    "part1",
+                    "part2",
+                    "part1 rev",
+                    "part3",
+                    "first 2",
+                    "first",
+                    "last",
+                ] {
+                    let (name, args) = line.split_once(' ').unwrap_or((line, ""));
+                    let args: Vec<_> = args.split_whitespace().collect();
+
+                    said.push(run(name, &args));
+                }
+            })
+            .unwrap();
+
+        assert_eq!(
+            said,
+            [
+                Ok("9".to_string()),
+                Ok("24".to_string()),
+                Ok("9".to_string()),
+                Err("no main for part 3".to_string()),
+                Ok("[2, 3]".to_string()),
+                Err("missing a count".to_string()),
+                Err("no command called last, try help".to_string()),
+            ]
+        );
+        assert_eq!(
+            Summer.commands(),
+            [("first", "first N: the first N numbers")]
+        );
+    }
+
+    #[test]
+    fn runs_only_the_parts_there_are() {
+        assert_eq!(Summer.run("2\n3", 2).unwrap().answer, Answer::Int(6));
+
+        let missing = std::panic::catch_unwind(|| Summer.run("2\n3", 3)).err().unwrap();
+
+        assert_eq!(
+            missing.downcast::<Unsolvable>().unwrap().0,
+            "there's no part 3, only 2"
+        );
+    }
+
+    #[test]
+    fn checks_examples() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let examples = dir.join("examples");
+
+        std::fs::create_dir_all(&examples).unwrap();
+        std::fs::write(examples.join("1.txt"), "2\n3\n4\n").unwrap();
+        std::fs::write(examples.join("1.answer"), "9\n").unwrap();
+        std::fs::write(examples.join("2.answer"), "24").unwrap();
+
+        check_examples::<Summer>(dir.to_str().unwrap());
+
+        std::fs::write(examples.join("2.answer"), "25").unwrap();
+        let wrong = std::panic::catch_unwind(|| check_examples::<Summer>(dir.to_str().unwrap()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(wrong.is_err());
+    }
+}
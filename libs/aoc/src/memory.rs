@@ -0,0 +1,194 @@
+//! A global allocator that can keep count, for finding out which parts eat
+//! all the memory. The runner installs it, but it only counts once enabled.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    // Counted per thread so parts running side by side don't get mixed up
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts::ZERO) };
+}
+
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    live: i64,
+    peak: i64,
+}
+
+impl Counts {
+    const ZERO: Self = Self {
+        allocations: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+/// What a stretch of code asked the allocator for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Memory {
+    pub allocations: u64,
+    /// Everything allocated, whether or not it was freed again
+    pub bytes: u64,
+    /// The most that was allocated at once
+    pub peak: u64,
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// Byte counts the way people read them
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{size:.1} {}", UNITS[unit])
+    }
+}
+
+/// The system allocator, plus counting
+pub struct Counting;
+
+fn count(allocated: usize, freed: usize) {
+    // Might be too late in the thread's life to count anything, which is fine
+    let _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated as u64;
+        }
+
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counts.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            count(layout.size(), 0);
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            count(layout.size(), 0);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+
+        if ENABLED.load(Ordering::Relaxed) {
+            count(0, layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+
+        if !new.is_null() && ENABLED.load(Ordering::Relaxed) {
+            count(new_size, layout.size());
+        }
+
+        new
+    }
+}
+
+/// Starts counting, which only does anything with [`Counting`] installed
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Runs `f`, along with what it allocated on this thread if counting's on
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Memory>) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+
+    let before = COUNTS.replace(Counts::ZERO);
+    let result = f();
+    let counts = COUNTS.replace(before);
+
+    let memory = Memory {
+        allocations: counts.allocations,
+        bytes: counts.bytes,
+        peak: counts.peak.max(0) as u64,
+    };
+
+    (result, Some(memory))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static COUNTING: Counting = Counting;
+
+    #[test]
+    fn counts_what_gets_allocated() {
+        assert_eq!(measure(|| vec![0u8; 10]).1, None);
+
+        enable();
+
+        let (_, memory) = measure(|| {
+            let small = vec![0u8; 1000];
+            drop(small);
+
+            let _big = vec![0u8; 4000];
+        });
+
+        assert_eq!(
+            memory,
+            Some(Memory {
+                allocations: 2,
+                bytes: 5000,
+                peak: 4000
+            })
+        );
+        assert_eq!(Bytes(5000).to_string(), "4.9 KiB");
+        assert_eq!(Bytes(12).to_string(), "12 B");
+    }
+}
@@ -0,0 +1,184 @@
+//! Positioned parse errors and the little helpers every day's parser leans on
+
+use std::{fmt::Display, str::FromStr};
+
+/// Something in the input we couldn't make sense of, and where it was
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Counted from 1, like editors do
+    pub line: usize,
+    /// Counted from 1, in characters
+    pub column: usize,
+    /// The offending bit of input
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Points at `text`, which should be a slice of `input`. Slices from
+    /// elsewhere get found by searching for them, or pinned to the start.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        let offset = if offset <= input.len() && input.is_char_boundary(offset) {
+            offset
+        } else {
+            input.find(text).unwrap_or(0)
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// For when the input just stops before we're done with it
+    pub fn eof(input: &str, message: impl Into<String>) -> Self {
+        let input = input.trim_end();
+
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    /// A compiler-ish report with the offending line and a marker under the
+    /// bad spot, with `path` being wherever `input` came from
+    pub fn diagnostic(&self, input: &str, path: &str) -> String {
+        let line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.column - 1);
+        let marker = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "error: {message}\n{gutter}--> {path}:{line_no}:{column}\n{gutter} |\n{number} | {line}\n{gutter} | {indent}{marker}",
+            message = self.message,
+            line_no = self.line,
+            column = self.column,
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+
+        if !self.text.is_empty() {
+            write!(f, " (found {:?})", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The input's lines with CRLF and trailing whitespace taken care of, and
+/// without the blank ones at the very end
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end().lines().map(str::trim_end)
+}
+
+/// Parses `text`, a slice of `input`, as a number
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, "expected a number"))
+}
+
+/// Reads one row of `line`, a slice of `input`, complaining about
+/// characters `cell` doesn't know
+pub fn row<T>(
+    input: &str,
+    line: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            cell(c).ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    &line[i..i + c.len_utf8()],
+                    format!("no idea what {c:?} is"),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Reads a rectangle of characters row by row, complaining about characters
+/// `cell` doesn't know and rows that don't line up with the first one
+pub fn grid<T>(
+    input: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for line in lines(input) {
+        let row = row(input, line, &mut cell)?;
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                let message = format!(
+                    "row is {} wide, but the first one is {}",
+                    row.len(),
+                    first.len()
+                );
+
+                return Err(ParseError::at(input, line, message));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_right_spot() {
+        let input = "1 2\r\n3 x  \r\n";
+        let lines: Vec<_> = lines(input).collect();
+
+        assert_eq!(lines, ["1 2", "3 x"]);
+
+        let error = number::<u32>(input, &lines[1][2..]).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.to_string(), "2:3: expected a number (found \"x\")");
+        assert_eq!(
+            error.diagnostic(input, "inputs/day0.txt"),
+            "error: expected a number\n --> inputs/day0.txt:2:3\n  |\n2 | 3 x  \n  |   ^"
+        );
+
+        let error = ParseError::eof(input, "wanted more");
+
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+
+    #[test]
+    fn reads_grids() {
+        let digit = |c: char| c.to_digit(10);
+
+        assert_eq!(
+            grid("12\r\n34\r\n", digit),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+
+        let error = grid("12\n3x", digit).unwrap_err();
+
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+
+        let error = grid("12\n345", digit).unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 1, "345")
+        );
+    }
+}
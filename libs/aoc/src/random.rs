@@ -0,0 +1,81 @@
+//! Seeded randomness for making up puzzle inputs. Hand-rolled so that a seed
+//! makes the same input on every machine, whatever crate versions we're on.
+
+/// SplitMix64, which is tiny and plenty random for making up inputs
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// Somewhere in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Nothing's below 0 man");
+
+        // Scales instead of taking a remainder, which favours the low numbers
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Somewhere in `start..end`
+    pub fn range(&mut self, range: std::ops::Range<usize>) -> usize {
+        range.start + self.below(range.len())
+    }
+
+    /// True about `odds` of the time
+    pub fn chance(&mut self, odds: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+
+        unit < odds
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+
+            (0..100).map(|_| rng.below(10)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|&n| n < 10));
+        // Everything shows up sooner or later
+        assert!((0..10).all(|n| numbers(7).contains(&n)));
+
+        let mut rng = Rng::new(1);
+        let mut items: Vec<_> = (0..20).collect();
+
+        rng.shuffle(&mut items);
+        items.sort();
+
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+        assert!((0..100).all(|_| (5..8).contains(&rng.range(5..8))));
+    }
+}
@@ -0,0 +1,315 @@
+//! The rectangle of characters half the puzzles hand us, parsed into
+//! whatever tiles a day likes, with the bounds checking done once
+
+pub mod direction;
+
+pub use direction::Direction;
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use aoc::{parse, ParseError};
+
+/// `(x, y)`, with `(0, 0)` in the top left corner
+pub type Coord = (usize, usize);
+
+/// Tiles in rows, stored row after row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Orthogonal steps, clockwise from up
+const FOUR: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Orthogonal and diagonal steps, clockwise from up
+const EIGHT: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+impl<T> Grid<T> {
+    /// Wraps `cells`, which go row after row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {width}x{height} grid wants {} cells man",
+            width * height
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// A grid with `value` everywhere
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Reads a rectangle of characters, complaining about characters `cell`
+    /// doesn't know and rows that don't line up
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let rows = parse::grid(input, cell)?;
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        Ok(Self::new(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every tile, row after row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.contains(coord).then(|| &self[coord])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            Some(&mut self[coord])
+        } else {
+            None
+        }
+    }
+
+    /// Where `coord` is in [`Grid::cells`], for days that keep their own
+    /// per-tile bookkeeping in a flat `Vec`
+    pub fn index_of(&self, (x, y): Coord) -> usize {
+        y * self.width + x
+    }
+
+    /// The other way around from [`Grid::index_of`]
+    pub fn coord_of(&self, index: usize) -> Coord {
+        (index % self.width, index / self.width)
+    }
+
+    /// Every coordinate, row after row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every tile along with where it is
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(&self.cells)
+    }
+
+    /// Where the first tile `matches` likes is, going row after row
+    pub fn find(&self, matches: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.cells.iter().position(matches).map(|index| self.coord_of(index))
+    }
+
+    /// `delta` away from `coord`, unless that's off the grid
+    pub fn offset(&self, (x, y): Coord, (delta_x, delta_y): (isize, isize)) -> Option<Coord> {
+        let next = (
+            x.checked_add_signed(delta_x)?,
+            y.checked_add_signed(delta_y)?,
+        );
+
+        self.contains(next).then_some(next)
+    }
+
+    /// One step `direction` of `coord`, unless that's off the grid
+    pub fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        self.offset(coord, direction.delta())
+    }
+
+    /// Up to `distance` steps away in any direction, Manhattan style, along
+    /// with how far each one is (`coord` itself included, at 0)
+    pub fn within(
+        &self,
+        coord: Coord,
+        distance: usize,
+    ) -> impl Iterator<Item = (usize, Coord)> + '_ {
+        let distance = distance as isize;
+
+        (-distance..=distance).flat_map(move |delta_x| {
+            let reach = distance - delta_x.abs();
+
+            (-reach..=reach).filter_map(move |delta_y| {
+                let next = self.offset(coord, (delta_x, delta_y))?;
+
+                Some(((delta_x.abs() + delta_y.abs()) as usize, next))
+            })
+        })
+    }
+
+    /// The up to four tiles sharing an edge with `coord`
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        FOUR.into_iter().filter_map(move |delta| self.offset(coord, delta))
+    }
+
+    /// The up to eight tiles sharing an edge or a corner with `coord`
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        EIGHT.into_iter().filter_map(move |delta| self.offset(coord, delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() doesn't like 0, and a 0 wide grid has no rows worth having
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Column `x`, top to bottom
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is off the grid man");
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The same shape, with every tile run through `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Coord) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "{:?} is off the {}x{} grid man",
+            (x, y),
+            self.width,
+            self.height
+        );
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Coord) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "{:?} is off the {}x{} grid man",
+            (x, y),
+            self.width,
+            self.height
+        );
+
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Draws the tiles back out, a line per row
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn finds_its_way_around() {
+        let grid = digits();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.find(|&n| n == 5), Some((1, 1)));
+        // Not square, so mixing up width and height would show
+        assert!((0..6).all(|i| grid.index_of(grid.coord_of(i)) == i));
+        assert_eq!(grid.coord_of(4), (1, 1));
+
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.rows().count(), 2);
+
+        let around: Vec<_> = grid.neighbours((0, 0)).collect();
+
+        assert_eq!(around, [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours((1, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+
+        assert_eq!(grid.step((0, 0), Direction::Right), Some((1, 0)));
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.within((0, 0), 2).count(), 5);
+        assert_eq!(grid.within((1, 1), 1).filter(|(d, _)| *d == 1).count(), 3);
+
+        assert!(Grid::parse("12\n3", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn draws_itself() {
+        let mut grid = digits().map(|&n| if n % 2 == 0 { '#' } else { '.' });
+
+        grid[(0, 0)] = '@';
+        grid.row_mut(1)[2] = 'x';
+
+        assert_eq!(grid.to_string(), "@#.\n#.x\n");
+        assert_eq!(Grid::filled(2, 1, 0).to_string(), "00\n");
+    }
+
+    #[test]
+    fn turns() {
+        use Direction::*;
+
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.reverse(), Right);
+        assert!(Direction::ALL.iter().all(|d| d.turn_left().turn_right() == *d));
+        assert_eq!(Direction::from_arrow('v'), Some(Down));
+        assert_eq!(Direction::from_arrow('x'), None);
+        assert!(Down.delta() == (0, 1) && !Down.is_horizontal());
+    }
+}
@@ -0,0 +1,236 @@
+//! Little tokenizers for the shapes puzzle lines come in, so days can stop
+//! reaching for regexes and `split_once(..).unwrap()` chains. Everything
+//! hands out slices of the input, so errors point at where it went wrong.
+
+use std::str::FromStr;
+
+use aoc::{parse, ParseError};
+
+/// Eats its way through `text`, a slice of `input`, from the front
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str, text: &'a str) -> Self {
+        Self { input, rest: text }
+    }
+
+    /// Whatever hasn't been eaten yet
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Complains about whatever hasn't been eaten yet
+    pub fn expected(&self, what: &str) -> ParseError {
+        ParseError::at(self.input, self.rest, format!("expected {what}"))
+    }
+
+    /// Eats as many characters as `matches` likes, which has to be at least
+    /// one
+    pub fn take_while(&mut self, matches: impl FnMut(char) -> bool) -> Option<&'a str> {
+        let rest = self.rest.trim_start_matches(matches);
+        let taken = &self.rest[..self.rest.len() - rest.len()];
+
+        if taken.is_empty() {
+            return None;
+        }
+
+        self.rest = rest;
+
+        Some(taken)
+    }
+
+    /// Eats exactly `literal`
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        self.rest = self
+            .rest
+            .strip_prefix(literal)
+            .ok_or_else(|| self.expected(&format!("{literal:?}")))?;
+
+        Ok(())
+    }
+
+    /// Eats whichever of `options` comes next, going for the longest when
+    /// more than one would do
+    pub fn one_of<'o>(&mut self, options: &[&'o str]) -> Result<&'o str, ParseError> {
+        let option = options
+            .iter()
+            .filter(|option| self.rest.starts_with(**option))
+            .max_by_key(|option| option.len())
+            .ok_or_else(|| self.expected(&format!("one of {}", options.join(", "))))?;
+
+        self.rest = &self.rest[option.len()..];
+
+        Ok(option)
+    }
+
+    /// Letters, digits and underscores, like names of things
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.take_while(|c| c.is_alphanumeric() || c == '_')
+            .ok_or_else(|| self.expected("a word"))
+    }
+
+    /// Digits, and nothing else
+    pub fn unsigned<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let digits = self
+            .take_while(|c| c.is_ascii_digit())
+            .ok_or_else(|| self.expected("a number"))?;
+
+        parse::number(self.input, digits)
+    }
+
+    /// Digits, maybe with a `-` or `+` in front
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let mut first = true;
+        let digits = self
+            .take_while(|c| {
+                let matched = (first && (c == '-' || c == '+')) || c.is_ascii_digit();
+                first = false;
+
+                matched
+            })
+            .ok_or_else(|| self.expected("a number"))?;
+
+        parse::number(self.input, digits)
+    }
+
+    /// At least one `item`, with `separator` in between each of them
+    pub fn list<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+
+        while let Some(rest) = self.rest.strip_prefix(separator) {
+            self.rest = rest;
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Makes sure there's nothing left over
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.expected("the end of the line"))
+        }
+    }
+}
+
+/// Scans all of `line`, a slice of `input`, with `scan`, complaining if it
+/// leaves anything over
+pub fn line<'a, T>(
+    input: &'a str,
+    line: &'a str,
+    scan: impl FnOnce(&mut Scanner<'a>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut scanner = Scanner::new(input, line);
+    let scanned = scan(&mut scanner)?;
+
+    scanner.end()?;
+
+    Ok(scanned)
+}
+
+/// Splits a `key: value` line, a slice of `input`
+pub fn key_value<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(": ")
+        .ok_or_else(|| ParseError::at(input, line, "expected a line like key: value"))
+}
+
+/// The runs of lines with blank lines between them, as slices of `input`
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = parse::lines(input).peekable();
+    let offset = move |line: &str| line.as_ptr() as usize - input.as_ptr() as usize;
+
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.is_empty()).is_some() {}
+
+        let first = lines.next()?;
+        let mut last = first;
+
+        while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+            last = line;
+        }
+
+        Some(&input[offset(first)..offset(last) + last.len()])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scans_lines() {
+        let input = "p=0,4 v=3,-3\nx00 AND y00 -> z00\n";
+        let (robot, gate) = input.split_once('\n').unwrap();
+
+        let scanned = line(input, robot, |s| {
+            s.literal("p=")?;
+            let position = s.list(",", Scanner::unsigned::<u32>)?;
+            s.literal(" v=")?;
+            let velocity = s.list(",", Scanner::signed::<i32>)?;
+
+            Ok((position, velocity))
+        });
+
+        assert_eq!(scanned, Ok((vec![0, 4], vec![3, -3])));
+
+        let mut s = Scanner::new(input, gate.trim_end());
+
+        assert_eq!(s.word(), Ok("x00"));
+        assert_eq!(s.literal(" "), Ok(()));
+        assert_eq!(s.one_of(&["AND", "OR", "XOR"]), Ok("AND"));
+        assert_eq!(s.rest(), " y00 -> z00");
+
+        let error = s.one_of(&["AND", "OR"]).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 8));
+        assert_eq!(error.message, "expected one of AND, OR");
+    }
+
+    #[test]
+    fn points_at_mistakes() {
+        let input = "12,x4\n-5\n";
+
+        let error = line(input, &input[..5], |s| s.list(",", Scanner::unsigned::<u8>)).unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.message, "expected a number");
+
+        let error = line(input, &input[..5], |s| s.unsigned::<u8>()).unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.message, "expected the end of the line");
+
+        assert!(line(input, &input[6..8], |s| s.unsigned::<i8>()).is_err());
+        assert_eq!(line(input, &input[6..8], |s| s.signed::<i8>()), Ok(-5));
+        // Too big is still a mistake
+        assert!(line("300", "300", |s| s.unsigned::<u8>()).is_err());
+    }
+
+    #[test]
+    fn splits_things_up() {
+        let input = "a: 1\nb: 2\n\n\nc: 3\r\n";
+        let blocks: Vec<_> = blocks(input).collect();
+
+        assert_eq!(blocks, ["a: 1\nb: 2", "c: 3"]);
+        assert_eq!(key_value(input, blocks[1]), Ok(("c", "3")));
+
+        let error = key_value(input, &input[0..2]).unwrap_err();
+
+        assert_eq!(error.text, "a:");
+        assert_eq!(super::blocks("\n\n").count(), 0);
+    }
+}
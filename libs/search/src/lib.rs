@@ -0,0 +1,390 @@
+//! Shortest paths over whatever a day's states happen to be, so nobody has
+//! to write out Dijkstra by hand at 6am again
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Anything a search can stand on, like a coordinate, or a coordinate and
+/// which way we're facing
+pub trait State: Clone + Eq + Hash {}
+
+impl<T: Clone + Eq + Hash> State for T {}
+
+/// What moving around costs. Steps are expected to cost something, or the
+/// predecessors of zero cost steps might not all get found.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Where you can get to from a state, and what that step costs. Closures
+/// returning anything iterable will do.
+pub trait Neighbours<S, C> {
+    fn neighbours(&mut self, state: &S, out: &mut Vec<(S, C)>);
+}
+
+impl<S, C, F, I> Neighbours<S, C> for F
+where
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    fn neighbours(&mut self, state: &S, out: &mut Vec<(S, C)>) {
+        out.extend(self(state));
+    }
+}
+
+/// A state waiting in the heap, cheapest (by `priority`) first
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap pops the biggest, we want the cheapest
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Everything a search remembers, kept around between searches so running
+/// lots of them doesn't mean allocating it all again each time.
+///
+/// Besides the cheapest cost to every state it reached, it keeps every
+/// predecessor that got there that cheaply, which makes a DAG of all the
+/// shortest paths for [`Search::on_shortest_paths`] and
+/// [`Search::count_paths`].
+pub struct Search<S, C> {
+    costs: HashMap<S, C>,
+    parents: HashMap<S, Vec<S>>,
+    heap: BinaryHeap<Queued<S, C>>,
+    frontier: VecDeque<S>,
+    scratch: Vec<(S, C)>,
+}
+
+impl<S: State, C: Cost> Default for Search<S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: State, C: Cost> Search<S, C> {
+    pub fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            heap: BinaryHeap::new(),
+            frontier: VecDeque::new(),
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Forgets the last search, but keeps the memory
+    fn reset(&mut self) {
+        self.costs.clear();
+        self.parents.clear();
+        self.heap.clear();
+        self.frontier.clear();
+    }
+
+    /// Records getting to `to` from `from` for `cost`, returning whether
+    /// that's the cheapest way there yet
+    fn reach(&mut self, from: &S, to: &S, cost: C) -> bool {
+        match self.costs.get(to) {
+            Some(&known) if cost > known => false,
+            Some(&known) if cost == known => {
+                self.parents.entry(to.clone()).or_default().push(from.clone());
+
+                false
+            }
+            _ => {
+                self.costs.insert(to.clone(), cost);
+                self.parents.insert(to.clone(), vec![from.clone()]);
+
+                true
+            }
+        }
+    }
+
+    /// Cheapest way from any of `starts` to a state `is_goal` likes,
+    /// returning that state and what it cost. A goal that never shows up
+    /// means searching everything reachable.
+    pub fn dijkstra(
+        &mut self,
+        starts: impl IntoIterator<Item = S>,
+        neighbours: impl Neighbours<S, C>,
+        is_goal: impl FnMut(&S) -> bool,
+    ) -> Option<(S, C)> {
+        self.astar(starts, neighbours, |_| C::default(), is_goal)
+    }
+
+    /// [`Search::dijkstra`], but trying the states `heuristic` thinks are
+    /// closer to a goal first. It mustn't overestimate, and shouldn't drop
+    /// by more than a step costs from one state to the next.
+    pub fn astar(
+        &mut self,
+        starts: impl IntoIterator<Item = S>,
+        mut neighbours: impl Neighbours<S, C>,
+        mut heuristic: impl FnMut(&S) -> C,
+        mut is_goal: impl FnMut(&S) -> bool,
+    ) -> Option<(S, C)> {
+        self.reset();
+
+        for start in starts {
+            self.costs.insert(start.clone(), C::default());
+            self.heap.push(Queued {
+                priority: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            });
+        }
+
+        let mut found: Option<(S, C)> = None;
+
+        while let Some(Queued {
+            priority,
+            cost,
+            state,
+        }) = self.heap.pop()
+        {
+            // Keeps going through anything just as cheap as the goal, so
+            // every way of getting there makes it into the DAG
+            if found.as_ref().is_some_and(|(_, best)| priority > *best) {
+                break;
+            }
+
+            if self.costs.get(&state).is_some_and(|&known| cost > known) {
+                // Got there cheaper since this was queued
+                continue;
+            }
+
+            if found.is_none() && is_goal(&state) {
+                found = Some((state.clone(), cost));
+            }
+
+            let mut next = std::mem::take(&mut self.scratch);
+
+            neighbours.neighbours(&state, &mut next);
+
+            for (to, step) in next.drain(..) {
+                let to_cost = cost + step;
+
+                if self.reach(&state, &to, to_cost) {
+                    self.heap.push(Queued {
+                        priority: to_cost + heuristic(&to),
+                        cost: to_cost,
+                        state: to,
+                    });
+                }
+            }
+
+            self.scratch = next;
+        }
+
+        found
+    }
+
+    /// What the last search paid to get to `state`, if it got there
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// Every state the last search got to, with what it cost
+    pub fn visited(&self) -> impl Iterator<Item = (&S, C)> {
+        self.costs.iter().map(|(state, cost)| (state, *cost))
+    }
+
+    /// The states `state` can be reached from as cheaply as possible, which
+    /// is none at all for the starts
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.parents.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One of the cheapest paths to `goal`, from a start up to and including
+    /// `goal`, or nothing if the last search never got there
+    pub fn path(&self, goal: &S) -> Vec<S> {
+        if !self.costs.contains_key(goal) {
+            return Vec::new();
+        }
+
+        let mut path = vec![goal.clone()];
+
+        while let Some(parent) = self.predecessors(path.last().unwrap()).first() {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+
+        path
+    }
+
+    /// Every state on any cheapest path to any of `goals`
+    pub fn on_shortest_paths(&self, goals: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut todo: Vec<_> = goals
+            .into_iter()
+            .filter(|goal| self.costs.contains_key(goal))
+            .collect();
+
+        while let Some(state) = todo.pop() {
+            if seen.insert(state.clone()) {
+                todo.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        seen
+    }
+
+    /// How many different cheapest paths there are to `goal`
+    pub fn count_paths(&self, goal: &S) -> u64 {
+        let mut states: Vec<_> = self.on_shortest_paths([goal.clone()]).into_iter().collect();
+
+        // Cheapest first, so predecessors are counted before they're needed
+        states.sort_by_key(|state| self.costs[state]);
+
+        let mut counts: HashMap<&S, u64> = HashMap::new();
+
+        for state in &states {
+            let parents = self.predecessors(state);
+            let count = if parents.is_empty() {
+                1
+            } else {
+                parents.iter().map(|parent| counts[parent]).sum()
+            };
+
+            counts.insert(state, count);
+        }
+
+        counts.get(goal).copied().unwrap_or(0)
+    }
+}
+
+impl<S: State> Search<S, usize> {
+    /// Fewest steps from any of `starts` to a state `is_goal` likes, for
+    /// when every step costs the same. Goes a lot quicker than
+    /// [`Search::dijkstra`] for it too.
+    pub fn bfs<I: IntoIterator<Item = S>>(
+        &mut self,
+        starts: impl IntoIterator<Item = S>,
+        mut neighbours: impl FnMut(&S) -> I,
+        mut is_goal: impl FnMut(&S) -> bool,
+    ) -> Option<(S, usize)> {
+        self.reset();
+
+        for start in starts {
+            if self.costs.insert(start.clone(), 0).is_none() {
+                self.frontier.push_back(start);
+            }
+        }
+
+        let mut found: Option<(S, usize)> = None;
+
+        while let Some(state) = self.frontier.pop_front() {
+            let cost = self.costs[&state];
+
+            if found.as_ref().is_some_and(|(_, best)| cost > *best) {
+                break;
+            }
+
+            if found.is_none() && is_goal(&state) {
+                found = Some((state.clone(), cost));
+            }
+
+            for to in neighbours(&state) {
+                if self.reach(&state, &to, cost + 1) {
+                    self.frontier.push_back(to);
+                }
+            }
+        }
+
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 to 4 two ways for 5 (through 1 or 2), and one way for 6 (through 3)
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 2), (2, 1), (3, 1)],
+            1 => vec![(4, 3)],
+            2 => vec![(4, 4)],
+            3 => vec![(4, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn finds_every_cheapest_way() {
+        let mut search = Search::new();
+
+        assert_eq!(search.dijkstra([0], edges, |&node| node == 4), Some((4, 5)));
+        assert_eq!(search.path(&4).first(), Some(&0));
+        assert_eq!(search.path(&4).len(), 3);
+        assert_eq!(search.predecessors(&4).len(), 2);
+        assert_eq!(search.count_paths(&4), 2);
+        assert_eq!(search.on_shortest_paths([4]), HashSet::from([0, 1, 2, 4]));
+
+        // Same scratch, different search
+        assert_eq!(search.dijkstra([3], edges, |&node| node == 4), Some((4, 5)));
+        assert_eq!(search.path(&4), [3, 4]);
+        assert_eq!(search.cost(&0), None);
+        assert!(search.path(&0).is_empty());
+
+        assert_eq!(search.dijkstra([0], edges, |&node| node == 9), None);
+        assert_eq!(search.visited().count(), 5);
+    }
+
+    #[test]
+    fn walks_a_grid() {
+        // 10x10 with a wall down x = 5, except at the bottom
+        let open =
+            |(x, y): (i32, i32)| (0..10).contains(&x) && (0..10).contains(&y) && (x != 5 || y == 9);
+        let steps = move |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(move |&next| open(next))
+        };
+        let goal = (9, 0);
+        let mut search = Search::new();
+
+        let (_, steps_taken) = search.bfs([(0, 0)], steps, |&at| at == goal).unwrap();
+
+        assert_eq!(steps_taken, 27);
+        assert_eq!(search.path(&goal).len(), 28);
+
+        let manhattan = |&(x, y): &(i32, i32)| ((goal.0 - x).abs() + (goal.1 - y).abs()) as usize;
+        let weighted = |at: &(i32, i32)| steps(at).map(|next| (next, 1));
+
+        assert_eq!(
+            search.astar([(0, 0)], weighted, manhattan, |&at| at == goal),
+            Some((goal, 27))
+        );
+        assert_eq!(
+            search.dijkstra([(0, 0)], weighted, |&at| at == goal),
+            Some((goal, 27))
+        );
+        // Only one way through the gap, but plenty of ways either side
+        assert!(search.count_paths(&goal) > 1);
+        assert!(search.on_shortest_paths([goal]).contains(&(5, 9)));
+    }
+}
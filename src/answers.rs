@@ -0,0 +1,223 @@
+use std::fmt::Display;
+
+use aoc::Answer;
+use toml::{Table, Value};
+
+/// Every year keeps its answers in a file of its own
+pub fn default_path(year: u32) -> String {
+    format!("answers/{year}.toml")
+}
+
+/// Where a day's rejected answers live, next to its parts
+const WRONG: &str = "wrong";
+
+/// Answers we know are right, kept as a table per day with parts for keys,
+/// plus whatever the site told us was wrong:
+///
+/// ```toml
+/// [day17]
+/// 1 = "1,5,7,4,1,6,0,3,0"
+/// 2 = 108107566389757
+///
+/// [day17.wrong]
+/// 2 = ["108107566389756"]
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    table: Table,
+}
+
+/// How an answer stacks up against the one on file
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Unknown,
+    Right,
+    Wrong { expected: String },
+}
+
+impl Answers {
+    /// A missing file is just an empty one
+    pub fn load(path: &str) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(saved) => Self::from_str(&saved).map_err(|error| format!("{path}: {error}")),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("can't read {path}: {e}")),
+        }
+    }
+
+    pub fn from_str(saved: &str) -> Result<Self, String> {
+        let table: Table = saved.parse().map_err(|e| format!("{e}"))?;
+
+        if let Some((day, _)) = table.iter().find(|(_, parts)| !parts.is_table()) {
+            return Err(format!("{day} should be a table of parts"));
+        }
+
+        Ok(Self { table })
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        if let Some(dir) = std::path::Path::new(path).parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+        }
+
+        std::fs::write(path, self.to_string()).map_err(|e| format!("can't write {path}: {e}"))
+    }
+
+    pub fn get(&self, day: &str, part: u8) -> Option<String> {
+        let answer = self.table.get(day)?.get(part.to_string())?;
+
+        Some(match answer {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+    }
+
+    pub fn is_known_wrong(&self, day: &str, part: u8, answer: &str) -> bool {
+        let wrong = || self.table.get(day)?.get(WRONG)?.get(part.to_string())?.as_array();
+
+        wrong().is_some_and(|wrong| wrong.iter().any(|known| known.as_str() == Some(answer)))
+    }
+
+    pub fn check(&self, day: &str, part: u8, answer: &Answer) -> Check {
+        match self.get(day, part) {
+            None => Check::Unknown,
+            Some(expected) if expected == answer.to_string() => Check::Right,
+            Some(expected) => Check::Wrong { expected },
+        }
+    }
+
+    /// Files `answer` away, overwriting whatever was there before
+    pub fn record(&mut self, day: &str, part: u8, answer: &Answer) {
+        let value = match answer {
+            Answer::Int(n) => Value::Integer(*n),
+            other => Value::String(other.to_string()),
+        };
+
+        self.day_mut(day).insert(part.to_string(), value);
+    }
+
+    /// Remembers that the site didn't like `answer`, so we don't try it twice
+    pub fn record_wrong(&mut self, day: &str, part: u8, answer: &str) {
+        if self.is_known_wrong(day, part, answer) {
+            return;
+        }
+
+        let wrong = self
+            .day_mut(day)
+            .entry(WRONG)
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .expect("wrong answers are tables man")
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Value::Array(wrong) = wrong {
+            wrong.push(Value::String(answer.to_string()));
+        }
+    }
+
+    fn day_mut(&mut self, day: &str) -> &mut Table {
+        self.table
+            .entry(day)
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .expect("days are tables man")
+    }
+}
+
+impl Display for Answers {
+    /// Days and parts in numeric order, so day10 doesn't land before day2
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut days: Vec<_> = self.table.iter().collect();
+        days.sort_by_key(|(day, _)| day_number(day));
+
+        let mut sorted = Table::new();
+
+        for (day, parts) in days {
+            let mut parts: Vec<_> = parts.as_table().into_iter().flatten().collect();
+            parts.sort_by_key(|(part, _)| part.parse::<u8>().unwrap_or(u8::MAX));
+
+            let parts = parts.into_iter().map(|(part, answer)| (part.clone(), answer.clone()));
+
+            sorted.insert(day.clone(), Value::Table(parts.collect()));
+        }
+
+        write!(f, "{sorted}")
+    }
+}
+
+fn day_number(day: &str) -> (u32, &str) {
+    let number = day.trim_start_matches("day").parse().unwrap_or(u32::MAX);
+
+    (number, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_recorded_answers() {
+        let mut answers = Answers::default();
+
+        answers.record("day18", 2, &Answer::Coord(27, 60));
+        answers.record("day1", 1, &Answer::Int(1889772));
+
+        assert_eq!(
+            answers.check("day1", 1, &Answer::Int(1889772)),
+            Check::Right
+        );
+        assert_eq!(
+            answers.check("day1", 1, &Answer::Int(42)),
+            Check::Wrong {
+                expected: "1889772".to_string()
+            }
+        );
+        assert_eq!(answers.check("day1", 2, &Answer::Int(42)), Check::Unknown);
+        assert_eq!(answers.check("day18", 2, &"27,60".into()), Check::Right);
+    }
+
+    #[test]
+    fn saves_in_order() {
+        let mut answers = Answers::default();
+
+        answers.record("day10", 2, &Answer::Int(2));
+        answers.record("day10", 1, &Answer::Int(1));
+        answers.record("day2", 1, &"a,b".into());
+
+        let saved = answers.to_string();
+
+        assert_eq!(saved, "[day2]\n1 = \"a,b\"\n\n[day10]\n1 = 1\n2 = 2\n");
+
+        let loaded = Answers::from_str(&saved).unwrap();
+
+        assert_eq!(loaded.get("day10", 2).as_deref(), Some("2"));
+        assert_eq!(loaded.get("day2", 1).as_deref(), Some("a,b"));
+        assert!(Answers::from_str("day1 = 5").is_err());
+    }
+
+    #[test]
+    fn remembers_wrong_answers() {
+        let mut answers = Answers::default();
+
+        answers.record("day17", 2, &Answer::Int(7));
+        answers.record_wrong("day17", 2, "6");
+        answers.record_wrong("day17", 2, "6");
+        answers.record_wrong("day17", 2, "8");
+
+        let saved = answers.to_string();
+
+        assert_eq!(
+            saved,
+            "[day17]\n2 = 7\n\n[day17.wrong]\n2 = [\"6\", \"8\"]\n"
+        );
+
+        let loaded = Answers::from_str(&saved).unwrap();
+
+        assert!(loaded.is_known_wrong("day17", 2, "8"));
+        assert!(!loaded.is_known_wrong("day17", 2, "7"));
+        assert!(!loaded.is_known_wrong("day17", 1, "8"));
+        assert_eq!(loaded.get("day17", 2).as_deref(), Some("7"));
+    }
+}
@@ -0,0 +1,105 @@
+/// Bare-bones argument handling: positionals in order, with `--name value`
+/// options and `--name` flags plucked out from anywhere on the line
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self {
+            args: std::env::args().skip(1).collect(),
+        }
+    }
+
+    /// Next positional argument
+    pub fn next(&mut self) -> Option<String> {
+        if self.args.is_empty() {
+            None
+        } else {
+            Some(self.args.remove(0))
+        }
+    }
+
+    /// Removes `--name value` or `--name=value`, whichever shows up first
+    pub fn option(&mut self, name: &str) -> Option<String> {
+        let flag = format!("--{name}");
+        let prefix = format!("--{name}=");
+
+        let index = self
+            .args
+            .iter()
+            .position(|arg| *arg == flag || arg.starts_with(&prefix))?;
+        let arg = self.args.remove(index);
+
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            return Some(value.to_string());
+        }
+
+        if index < self.args.len() {
+            Some(self.args.remove(index))
+        } else {
+            panic!("{flag} wants a value");
+        }
+    }
+
+    /// Removes `--name N` for a count of something that there has to be at
+    /// least one of, or gives `default` if it isn't there
+    pub fn positive(&mut self, name: &str, default: usize) -> Result<usize, String> {
+        let Some(value) = self.option(name) else {
+            return Ok(default);
+        };
+
+        match value.parse() {
+            Ok(0) | Err(_) => Err(format!("--{name} wants a number above zero, not {value:?}")),
+            Ok(n) => Ok(n),
+        }
+    }
+
+    /// Removes a bare `--name`, returning whether it was there
+    pub fn flag(&mut self, name: &str) -> bool {
+        let flag = format!("--{name}");
+
+        if let Some(index) = self.args.iter().position(|arg| *arg == flag) {
+            self.args.remove(index);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Removes every `-x`, `-xx` and so on, returning how many x's there were
+    pub fn count(&mut self, short: char) -> usize {
+        let mut count = 0;
+
+        self.args.retain(|arg| match arg.strip_prefix('-') {
+            Some(letters) if !letters.is_empty() && letters.chars().all(|c| c == short) => {
+                count += letters.len();
+
+                false
+            }
+            _ => true,
+        });
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Args {
+        Args {
+            args: line.split(' ').map(String::from).collect(),
+        }
+    }
+
+    #[test]
+    fn positive() {
+        assert_eq!(args("bench").positive("samples", 10), Ok(10));
+        assert_eq!(args("bench --samples 3").positive("samples", 10), Ok(3));
+        assert!(args("bench --samples 0").positive("samples", 10).is_err());
+        assert!(args("bench --samples=lots").positive("samples", 10).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use aoc::{Answer, Day, MAIN_VARIANT};
+
+use crate::runner;
+
+pub struct Options {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+/// Summary of a bunch of timed samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub samples: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Can't summarize zero samples man");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        // Sample standard deviation, which is zero with a single sample
+        let variance = if secs.len() > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (secs.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            samples: samples.len(),
+        }
+    }
+}
+
+/// One benchmarked (day, part, variant) triple
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub day: String,
+    pub part: u8,
+    pub variant: String,
+    pub stats: Stats,
+}
+
+impl Row {
+    fn key(&self) -> (&str, u8, &str) {
+        (&self.day, self.part, &self.variant)
+    }
+}
+
+/// Times every variant of the given parts, printing rows as they finish
+/// along with how they moved compared to the matching rows in `before`
+pub fn bench(days: &[&Day], part: Option<u8>, options: &Options, before: &[Row]) -> Vec<Row> {
+    let before: HashMap<_, _> = before.iter().map(|row| (row.key(), &row.stats)).collect();
+    let mut rows = Vec::new();
+
+    print_header();
+
+    for day in days {
+        let input = match runner::read_input(day, None) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("skipping {name}: {error}", name = day.name());
+
+                continue;
+            }
+        };
+
+        for part in day.parts().filter(|p| part.is_none_or(|part| part == *p)) {
+            let mut main_answer = None;
+
+            for variant in day.solver.variants(part) {
+                let mut samples = Vec::with_capacity(options.samples);
+                let mut answer = None;
+
+                let prepared = day.solver.prepare(&input.text, part, variant, &mut |solve| {
+                    for _ in 0..options.warmup {
+                        std::hint::black_box(solve());
+                    }
+
+                    for _ in 0..options.samples {
+                        let start = Instant::now();
+                        let result = std::hint::black_box(solve());
+                        samples.push(start.elapsed());
+
+                        answer = Some(result);
+                    }
+                });
+
+                if let Err(error) = prepared {
+                    eprintln!("{}", error.diagnostic(&input.text, &input.path));
+
+                    break;
+                }
+
+                let row = Row {
+                    day: day.name(),
+                    part,
+                    variant: variant.to_string(),
+                    stats: Stats::from_samples(&samples),
+                };
+
+                print_row(&row, before.get(&row.key()).copied());
+
+                if variant == MAIN_VARIANT {
+                    main_answer = answer;
+                } else if answer != main_answer {
+                    eprintln!(
+                        "{variant} disagrees with {MAIN_VARIANT}: {} vs {}",
+                        show(&answer),
+                        show(&main_answer)
+                    );
+                }
+
+                rows.push(row);
+            }
+        }
+    }
+
+    rows
+}
+
+fn show(answer: &Option<Answer>) -> String {
+    answer
+        .as_ref()
+        .map_or_else(|| "nothing".to_string(), Answer::to_string)
+}
+
+fn print_header() {
+    println!(
+        "{:<6} {:>4} {:<14} {:>12} {:>12} {:>12}",
+        "day", "part", "variant", "median", "mean", "stddev"
+    );
+}
+
+/// Prints a row, with how its median moved since `before` if we have one
+fn print_row(row: &Row, before: Option<&Stats>) {
+    let Stats {
+        median,
+        mean,
+        stddev,
+        ..
+    } = row.stats;
+
+    let change = before
+        .map(|before| {
+            let ratio = median.as_secs_f64() / before.median.as_secs_f64();
+
+            format!(
+                "  {:+.1}% (was {:.2?})",
+                (ratio - 1.0) * 100.0,
+                before.median
+            )
+        })
+        .unwrap_or_default();
+
+    println!(
+        "{:<6} {:>4} {:<14} {median:>12.2?} {mean:>12.2?} {stddev:>12.2?}{change}",
+        row.day, row.part, row.variant
+    );
+}
+
+const HEADER: &str = "# day\tpart\tvariant\tmedian_ns\tmean_ns\tstddev_ns\tsamples";
+
+/// Tab separated, one row per line, so two runs can be diffed or compared
+pub fn save(rows: &[Row]) -> String {
+    let mut out = format!("{HEADER}\n");
+
+    for row in rows {
+        let Stats {
+            median,
+            mean,
+            stddev,
+            samples,
+        } = row.stats;
+
+        out += &format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{samples}\n",
+            row.day,
+            row.part,
+            row.variant,
+            median.as_nanos(),
+            mean.as_nanos(),
+            stddev.as_nanos()
+        );
+    }
+
+    out
+}
+
+pub fn load(saved: &str) -> Result<Vec<Row>, String> {
+    saved
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|(i, line)| load_row(line).ok_or_else(|| format!("bad bench row on line {}", i + 1)))
+        .collect()
+}
+
+fn load_row(line: &str) -> Option<Row> {
+    let mut fields = line.trim_end().split('\t');
+    let day = fields.next()?.to_string();
+    let part = fields.next()?.parse().ok()?;
+    let variant = fields.next()?.to_string();
+    let mut nanos = || fields.next()?.parse().ok().map(Duration::from_nanos);
+    let (median, mean, stddev) = (nanos()?, nanos()?, nanos()?);
+    let samples = fields.next()?.parse().ok()?;
+
+    Some(Row {
+        day,
+        part,
+        variant,
+        stats: Stats {
+            median,
+            mean,
+            stddev,
+            samples,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(3), ms(2)]);
+
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        // sqrt(5 / 3) ms
+        assert_eq!(stats.stddev.as_micros(), 1290);
+
+        let single = Stats::from_samples(&[ms(7)]);
+
+        assert_eq!(single.median, ms(7));
+        assert_eq!(single.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn saves_and_loads() {
+        let rows = vec![
+            Row {
+                day: "day4".to_string(),
+                part: 2,
+                variant: "regex".to_string(),
+                stats: Stats::from_samples(&[ms(1), ms(2), ms(3)]),
+            },
+            Row {
+                day: "day11".to_string(),
+                part: 1,
+                variant: MAIN_VARIANT.to_string(),
+                stats: Stats::from_samples(&[ms(12)]),
+            },
+        ];
+
+        assert_eq!(load(&save(&rows)).unwrap(), rows);
+        assert!(load("day1\t1\tmain\tlots").is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+use crate::config::Config;
+
+/// Checked first, before the config file and `session.txt`
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = "session.txt";
+
+/// Finds the session cookie in the environment, then the config, then `session.txt`
+pub fn find_session(config: &Config) -> Result<String, String> {
+    let from_env = std::env::var(SESSION_VAR).ok();
+    let from_file = || std::fs::read_to_string(SESSION_FILE).ok();
+
+    from_env
+        .or_else(|| config.session.clone())
+        .or_else(from_file)
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or_else(|| {
+            format!("no session cookie: set {SESSION_VAR}, add `session` to the config or put it in {SESSION_FILE}")
+        })
+}
+
+/// Talks to the puzzle site, or whatever stands in for it
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    year: u32,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, year: u32, session: String) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent("github.com/Dorumin/aoc2024")
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year,
+            session,
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        Ok(Self::new(
+            &config.base_url,
+            config.year,
+            find_session(config)?,
+        ))
+    }
+
+    pub fn input(&self, day: u32) -> Result<String, String> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+
+        self.agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|error| describe(&url, error))?
+            .into_string()
+            .map_err(|e| format!("couldn't read the response from {url}: {e}"))
+    }
+
+    /// The puzzle page, which only has part two once part one is solved
+    pub fn puzzle(&self, day: u32) -> Result<String, String> {
+        let url = format!("{}/{}/day/{day}", self.base_url, self.year);
+
+        self.agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|error| describe(&url, error))?
+            .into_string()
+            .map_err(|e| format!("couldn't read the response from {url}: {e}"))
+    }
+
+    /// Posts an answer and hands back the page the site replies with
+    pub fn submit(&self, day: u32, part: u8, answer: &str) -> Result<String, String> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+
+        self.agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| describe(&url, error))?
+            .into_string()
+            .map_err(|e| format!("couldn't read the response from {url}: {e}"))
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+/// Turns the site's status codes into something a person can act on
+fn describe(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(400, _) => {
+            format!("{url} said 400 bad request, the session cookie is probably stale")
+        }
+        ureq::Error::Status(404, _) => {
+            format!("{url} said 404 not found, that puzzle isn't out yet or doesn't exist")
+        }
+        ureq::Error::Status(code @ 500.., _) => {
+            format!("{url} said {code}, the site is struggling or didn't like the session cookie")
+        }
+        ureq::Error::Status(code, response) => {
+            format!("{url} said {code} {}", response.status_text())
+        }
+        ureq::Error::Transport(transport) => format!("couldn't get through: {transport}"),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use std::thread::JoinHandle;
+
+    use super::*;
+
+    /// What the stand-in server saw
+    pub struct Request {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    /// Starts a local server that answers one request per response, in
+    /// order, and hands back its base url and everything it was sent
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+
+        let handle = std::thread::spawn(move || {
+            let mut seen = Vec::new();
+
+            for (status, body) in responses {
+                let mut request = server.recv().unwrap();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+                let mut sent = String::new();
+                request.as_reader().read_to_string(&mut sent).unwrap();
+
+                seen.push(Request {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie,
+                    body: sent,
+                });
+
+                let response = tiny_http::Response::from_string(body).with_status_code(status);
+                request.respond(response).unwrap();
+            }
+
+            seen
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, server) = serve(vec![(200, "1 2\n3 4\n")]);
+        let client = Client::new(&base_url, 2015, "cookie".to_string());
+
+        assert_eq!(client.input(3).unwrap(), "1 2\n3 4\n");
+
+        let seen = server.join().unwrap();
+
+        assert_eq!(seen[0].method, "GET");
+        assert_eq!(seen[0].url, "/2015/day/3/input");
+        assert_eq!(seen[0].cookie.as_deref(), Some("session=cookie"));
+    }
+
+    #[test]
+    fn explains_errors() {
+        let (base_url, server) = serve(vec![
+            (400, "Puzzle inputs differ by user."),
+            (404, "Not Found"),
+            (500, "Internal Server Error"),
+        ]);
+        let client = Client::new(&base_url, 2024, "cookie".to_string());
+
+        assert!(client.input(1).unwrap_err().contains("stale"));
+        assert!(client.input(26).unwrap_err().contains("isn't out yet"));
+        assert!(client.input(1).unwrap_err().contains("500"));
+
+        server.join().unwrap();
+    }
+}
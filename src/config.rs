@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use toml::{Table, Value};
+
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+/// Settings from `aoc.toml`, every one of them optional:
+///
+/// ```toml
+/// year = 2024 # for when the command line doesn't start with one
+/// base_url = "https://adventofcode.com"
+/// session = "53616c7465645f5f..."
+/// timeout = 30 # seconds per part, none by default
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub year: u32,
+    pub base_url: String,
+    pub session: Option<String>,
+    pub timeout: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: 2024,
+            base_url: "https://adventofcode.com".to_string(),
+            session: None,
+            timeout: None,
+        }
+    }
+}
+
+impl Config {
+    /// A missing file gets you the defaults
+    pub fn load(path: &str) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(config) => Self::from_str(&config).map_err(|error| format!("{path}: {error}")),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("can't read {path}: {e}")),
+        }
+    }
+
+    pub fn from_str(config: &str) -> Result<Self, String> {
+        let table: Table = config.parse().map_err(|e| format!("{e}"))?;
+        let mut config = Self::default();
+
+        for (key, value) in table {
+            match (key.as_str(), value) {
+                ("year", Value::Integer(year)) => {
+                    config.year = year.try_into().map_err(|_| format!("{year} isn't a year"))?;
+                }
+                ("base_url", Value::String(url)) => config.base_url = url,
+                ("session", Value::String(session)) => config.session = Some(session),
+                ("timeout", Value::Integer(seconds)) => {
+                    config.timeout = Some(seconds_of(seconds as f64)?)
+                }
+                ("timeout", Value::Float(seconds)) => config.timeout = Some(seconds_of(seconds)?),
+                (key, value) => return Err(format!("don't know what to do with {key} = {value}")),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Reads a time limit in seconds, like `--timeout 2.5`
+pub fn seconds_of(seconds: f64) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("{seconds} isn't a time limit"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_in_defaults() {
+        let config = Config::from_str("year = 2023\nsession = \"cookie\"\ntimeout = 1.5").unwrap();
+
+        assert_eq!(config.year, 2023);
+        assert_eq!(config.base_url, Config::default().base_url);
+        assert_eq!(config.session.as_deref(), Some("cookie"));
+        assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+
+        assert!(Config::from_str("year = \"next\"").is_err());
+        assert!(Config::from_str("colour = \"blue\"").is_err());
+        assert!(Config::from_str("timeout = -1").is_err());
+    }
+}
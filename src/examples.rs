@@ -0,0 +1,213 @@
+use std::path::Path;
+
+use crate::{client::Client, config::Config, scaffold};
+
+/// What one part of the puzzle page gives away
+#[derive(Debug, Default, PartialEq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Pulls an example out of every part on the page: the first `<pre><code>`
+/// block is the input, and the last highlighted `<code><em>` is the answer
+pub fn extract(html: &str) -> Vec<Example> {
+    html.split("<article")
+        .skip(1)
+        .map(|article| {
+            let article = article.split("</article>").next().unwrap_or_default();
+
+            Example {
+                input: between(article, "<pre><code>", "</code></pre>").map(text),
+                answer: last_answer(article).map(text),
+            }
+        })
+        .collect()
+}
+
+fn between<'a>(haystack: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = haystack.split_once(start)?;
+    let (inside, _) = rest.split_once(end)?;
+
+    Some(inside)
+}
+
+/// The site highlights answers as `<code><em>` or the other way around
+fn last_answer(article: &str) -> Option<&str> {
+    let last = |start: &str, end: &str| {
+        let (before, rest) = article.rsplit_once(start)?;
+        let (inside, _) = rest.split_once(end)?;
+
+        Some((before.len(), inside))
+    };
+
+    let code_em = last("<code><em>", "</em></code>");
+    let em_code = last("<em><code>", "</code></em>");
+
+    code_em.max(em_code).map(|(_, answer)| answer)
+}
+
+/// Drops the markup and undoes the escaping
+pub fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Writes `days/YYYY/dayN/examples/{part}.txt` and `{part}.answer`, skipping
+/// inputs that are the same as part one's. Returns how many answers it saved.
+pub fn save(root: &Path, year: u32, day: u32, examples: &[Example]) -> Result<usize, String> {
+    let dir = root.join(scaffold::dir(year, day)).join("examples");
+    let write = |name: String, contents: &str| {
+        let path = dir.join(name);
+
+        std::fs::write(&path, contents).map_err(|e| format!("can't write {}: {e}", path.display()))
+    };
+
+    std::fs::create_dir_all(&dir).map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+
+    let first_input = examples.first().and_then(|example| example.input.as_ref());
+    let mut answers = 0;
+
+    for (part, example) in (1..).zip(examples) {
+        if let Some(input) = &example.input {
+            if part == 1 || Some(input) != first_input {
+                write(format!("{part}.txt"), input)?;
+            }
+        }
+
+        if let Some(answer) = &example.answer {
+            write(format!("{part}.answer"), &format!("{answer}\n"))?;
+            answers += 1;
+        }
+    }
+
+    Ok(answers)
+}
+
+/// Whether a day already has example fixtures saved, going by part one's input
+pub fn saved(root: &Path, year: u32, day: u32) -> bool {
+    root.join(scaffold::dir(year, day)).join("examples/1.txt").exists()
+}
+
+/// Where puzzle pages get kept, so examples can be pulled out again offline
+pub fn page_path(year: u32, day: u32) -> String {
+    format!("puzzles/{year}/day{day}.html")
+}
+
+/// Gets the puzzle page from `html` if given, else from the saved copy,
+/// downloading (and saving) it when there's none or `refresh` is set
+pub fn page(
+    config: &Config,
+    day: u32,
+    html: Option<&str>,
+    refresh: bool,
+) -> Result<String, String> {
+    if let Some(html) = html {
+        return std::fs::read_to_string(html).map_err(|e| format!("can't read {html}: {e}"));
+    }
+
+    let path = page_path(config.year, day);
+
+    if !refresh {
+        if let Ok(page) = std::fs::read_to_string(&path) {
+            return Ok(page);
+        }
+    }
+
+    let page = Client::from_config(config)?.puzzle(day)?;
+
+    let dir = format!("puzzles/{}", config.year);
+
+    std::fs::create_dir_all(&dir).map_err(|e| format!("can't create {dir}: {e}"))?;
+    std::fs::write(&path, &page).map_err(|e| format!("can't write {path}: {e}"))?;
+
+    Ok(page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><body><main>
+<article class="day-desc"><h2>--- Day 5: Print Queue ---</h2><p>For example:</p>
+<pre><code>47|53
+97|13
+
+75,47,61,53,29
+</code></pre>
+<p>The first rule, <code>47|53</code>, means...</p>
+<pre><code>75,<em>47</em>,61</code></pre>
+<p>Adding these together produces <code><em>143</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>4905</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Something &lt;weird&gt; &amp; more: <em><code>123</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn extracts_examples() {
+        let examples = extract(PAGE);
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    input: Some("47|53\n97|13\n\n75,47,61,53,29\n".to_string()),
+                    answer: Some("143".to_string()),
+                },
+                Example {
+                    input: None,
+                    answer: Some("123".to_string()),
+                },
+            ]
+        );
+
+        assert_eq!(text("<em>a</em> &lt;&amp;&gt;"), "a <&>");
+    }
+
+    #[test]
+    fn saves_fixtures() {
+        let root = std::env::temp_dir().join(format!("aoc2024-examples-{}", std::process::id()));
+        let examples = vec![
+            Example {
+                input: Some("1 2\n".to_string()),
+                answer: Some("3".to_string()),
+            },
+            Example {
+                input: Some("1 2\n".to_string()),
+                answer: Some("2".to_string()),
+            },
+        ];
+
+        assert!(!saved(&root, 2024, 9));
+        assert_eq!(save(&root, 2024, 9, &examples), Ok(2));
+        assert!(saved(&root, 2024, 9));
+
+        let dir = root.join("days/2024/day9/examples");
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).ok();
+
+        assert_eq!(read("1.txt").as_deref(), Some("1 2\n"));
+        assert_eq!(read("1.answer").as_deref(), Some("3\n"));
+        assert_eq!(read("2.txt"), None);
+        assert_eq!(read("2.answer").as_deref(), Some("2\n"));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,144 @@
+//! Where the days' `log` output ends up. Quiet unless asked, so solvers can
+//! keep their diagnostics around without cluttering runs or slowing benches.
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+use crate::scaffold;
+
+/// Levels for everything, with overrides for particular days (or modules)
+#[derive(Debug, PartialEq, Eq)]
+pub struct Logger {
+    default: LevelFilter,
+    /// Which year's crate a bare `dayN` means
+    year: u32,
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Logger {
+    /// Warnings only, plus one level per `-v`
+    pub fn new(verbosity: usize, year: u32) -> Self {
+        let default = match verbosity {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Info,
+            2 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        };
+
+        Self {
+            default,
+            year,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Takes things like `day16=trace`, `debug` or `day6=info,day9=trace`,
+    /// where a bare level goes for everything. Days are this year's unless
+    /// they're spelled out like `y2023_day16`.
+    pub fn add_spec(&mut self, spec: &str) -> Result<(), String> {
+        for part in spec.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            match part.split_once('=') {
+                Some((target, level)) => {
+                    self.targets.push((self.crate_target(target), level_of(level)?));
+                }
+                None => self.default = level_of(part)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `dayN` (and its modules) as the target the day's crate logs under
+    fn crate_target(&self, target: &str) -> String {
+        let (day, modules) = target.split_once("::").unwrap_or((target, ""));
+        let number = day.strip_prefix("day").and_then(|n| n.parse().ok());
+
+        match number {
+            Some(number) if modules.is_empty() => scaffold::package(self.year, number),
+            Some(number) => format!("{}::{modules}", scaffold::package(self.year, number)),
+            None => target.to_string(),
+        }
+    }
+
+    /// What `target` gets to log, going by the most specific match
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(name, _)| {
+                target == name
+                    || target
+                        .strip_prefix(name.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(name, _)| name.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// Makes this the logger, which only works once
+    pub fn install(self) {
+        // Lets the macros skip anything nobody wants before formatting it
+        let max = self
+            .targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max);
+
+        log::set_boxed_logger(Box::new(self)).expect("Only one logger man");
+        log::set_max_level(max);
+    }
+}
+
+fn level_of(name: &str) -> Result<LevelFilter, String> {
+    name.parse().map_err(|_| {
+        format!("{name:?} isn't a log level, try off, error, warn, info, debug or trace")
+    })
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_levels_per_day() {
+        let mut logger = Logger::new(1, 2024);
+
+        logger.add_spec("day16=trace, day1=off").unwrap();
+        logger.add_spec("day16::maze=warn").unwrap();
+
+        // Days log under their crate's name
+        assert_eq!(logger.level_for("y2024_day5"), LevelFilter::Info);
+        assert_eq!(logger.level_for("y2024_day16"), LevelFilter::Trace);
+        assert_eq!(logger.level_for("y2024_day16::paths"), LevelFilter::Trace);
+        assert_eq!(logger.level_for("y2024_day16::maze"), LevelFilter::Warn);
+        assert_eq!(logger.level_for("y2024_day1"), LevelFilter::Off);
+        // Not a module of day1
+        assert_eq!(logger.level_for("y2024_day10"), LevelFilter::Info);
+        // Nor this year's
+        assert_eq!(logger.level_for("y2023_day16"), LevelFilter::Info);
+
+        logger.add_spec("y2023_day16=debug, search=trace").unwrap();
+
+        assert_eq!(logger.level_for("y2023_day16"), LevelFilter::Debug);
+        assert_eq!(logger.level_for("search"), LevelFilter::Trace);
+
+        logger.add_spec("debug").unwrap();
+
+        assert_eq!(logger.level_for("y2024_day5"), LevelFilter::Debug);
+        assert!(logger.add_spec("day5=loud").is_err());
+        assert_eq!(Logger::new(0, 2024).default, LevelFilter::Warn);
+        assert_eq!(Logger::new(7, 2024).default, LevelFilter::Trace);
+    }
+}
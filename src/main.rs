@@ -1,67 +1,353 @@
-fn dispatch(p1: fn(), p2: fn(), part: &str) {
-    match part {
-        "1" => p1(),
-        "2" => p2(),
-        _ => panic!("There's only two parts"),
-    }
-}
+mod answers;
+mod args;
+mod bench;
+mod client;
+mod config;
+mod examples;
+mod logger;
+mod registry;
+mod repl;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
+
+// Doesn't count anything until --memory asks it to
+#[global_allocator]
+static ALLOCATOR: aoc::memory::Counting = aoc::memory::Counting;
 
 fn main() {
-    let mut vargs = std::env::args().skip(1);
-    let its_a_date = vargs.next().expect("Pass an argument man");
-    let parte = vargs.next().expect("Pass an argument man");
+    let mut args = args::Args::from_env();
+    let verbosity = args.count('v');
+    let mut log_specs = Vec::new();
+    while let Some(spec) = args.option("log") {
+        log_specs.push(spec);
+    }
+    let memory = args.flag("memory");
+    if memory {
+        aoc::memory::enable();
+    }
+    let mut config =
+        config::Config::load(config::DEFAULT_PATH).unwrap_or_else(|error| panic!("{error}"));
+    if let Some(year) = args.option("year") {
+        config.year = year.parse().expect("Years are numbers man");
+    }
+    if let Some(base_url) = args.option("base-url") {
+        config.base_url = base_url;
+    }
+    if let Some(timeout) = args.option("timeout") {
+        let seconds = timeout.parse().expect("Timeouts are seconds man");
+
+        config.timeout =
+            Some(config::seconds_of(seconds).unwrap_or_else(|error| panic!("{error}")));
+    }
+    let input_path = args.option("input");
+    let format = args.option("format").map_or(runner::Format::Table, |name| {
+        runner::Format::from_name(&name).unwrap_or_else(|error| panic!("{error}"))
+    });
+    let answers_path = args.option("answers");
+    let mut its_a_date = args.next().expect("Pass an argument man");
+    // Like `2023 day5 1`, for a year other than the usual
+    if let Ok(year) = its_a_date.parse() {
+        config.year = year;
+        its_a_date = args.next().expect("Pass an argument man");
+    }
+    // Waits for the year, since that's whose days `--log dayN` means
+    let mut logger = logger::Logger::new(verbosity, config.year);
+    for spec in &log_specs {
+        logger.add_spec(spec).unwrap_or_else(|error| panic!("{error}"));
+    }
+    logger.install();
+    let answers_path = answers_path.unwrap_or_else(|| answers::default_path(config.year));
+    let mut answers =
+        answers::Answers::load(&answers_path).unwrap_or_else(|error| panic!("{error}"));
 
     match its_a_date.as_ref() {
-        "day1" => dispatch(day1::part1, day1::part2, &parte),
-        "day2" => dispatch(day2::part1, day2::part2, &parte),
-        "day3" => dispatch(day3::part1, day3::part2, &parte),
-        "day4" => dispatch(day4::part1, day4::part2, &parte),
-        "day5" => dispatch(day5::part1, day5::part2, &parte),
-        "day6" => dispatch(day6::part1, day6::part2, &parte),
-        "day7" => dispatch(day7::part1, day7::part2, &parte),
-        "day8" => dispatch(day8::part1, day8::part2, &parte),
-        "day9" => dispatch(day9::part1, day9::part2, &parte),
-        "day10" => dispatch(day10::part1, day10::part2, &parte),
-        "day11" => dispatch(day11::part1, day11::part2, &parte),
-        "day12" => dispatch(day12::part1, day12::part2, &parte),
-        "day13" => dispatch(day13::part1, day13::part2, &parte),
-        "day14" => dispatch(day14::part1, day14::part2, &parte),
-        "day15" => dispatch(day15::part1, day15::part2, &parte),
-        "day16" => dispatch(day16::part1, day16::part2, &parte),
-        "day17" => dispatch(day17::part1, day17::part2, &parte),
-        "day18" => dispatch(day18::part1, day18::part2, &parte),
-        "day19" => dispatch(day19::part1, day19::part2, &parte),
-        "day20" => dispatch(day20::part1, day20::part2, &parte),
-        "day21" => dispatch(day21::part1, day21::part2, &parte),
-        "day22" => dispatch(day22::part1, day22::part2, &parte),
-        "day23" => dispatch(day23::part1, day23::part2, &parte),
-        "day24" => dispatch(day24::part1, day24::part2, &parte),
-        "day25" => dispatch(day25::part1, day25::part2, &parte),
+        "list" => {
+            for day in registry::year(config.year) {
+                let parts: Vec<_> = day.parts().map(|part| part.to_string()).collect();
+
+                println!(
+                    "{name}: parts {parts}",
+                    name = day.name(),
+                    parts = parts.join(", ")
+                );
+            }
+        }
+        "all" => {
+            let options = runner::Options {
+                year: config.year,
+                fail_fast: args.flag("fail-fast"),
+                jobs: args
+                    .option("jobs")
+                    .map_or(1, |n| n.parse().expect("Jobs is a number man")),
+                format,
+                timeout: config.timeout,
+                memory,
+            };
+
+            let ok = runner::run_all(&options, &mut answers);
+
+            answers.save(&answers_path).unwrap_or_else(|error| panic!("{error}"));
+
+            if !ok {
+                std::process::exit(1);
+            }
+        }
+        "verify" => {
+            if !runner::verify(&answers, config.year, config.timeout) {
+                std::process::exit(1);
+            }
+        }
+        "bench" => {
+            let options = bench::Options {
+                warmup: args
+                    .option("warmup")
+                    .map_or(1, |n| n.parse().expect("Warmup is a number man")),
+                samples: args.positive("samples", 10).unwrap_or_else(|error| {
+                    eprintln!("{error}");
+                    std::process::exit(1);
+                }),
+            };
+            let save = args.option("save").unwrap_or_else(|| "bench_output.txt".to_string());
+            let before = args.option("compare").map(|path| {
+                let saved = std::fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("can't read {path}: {e}"));
+
+                bench::load(&saved).unwrap_or_else(|error| panic!("{path}: {error}"))
+            });
+
+            let days: Vec<_> = match args.next() {
+                Some(day) => vec![registry::find(config.year, &day).expect("No day that matches")],
+                None => registry::year(config.year).collect(),
+            };
+            let part = args.next().map(|part| part.parse().expect("Parts are numbers man"));
+
+            let rows = bench::bench(&days, part, &options, before.as_deref().unwrap_or_default());
+
+            std::fs::write(&save, bench::save(&rows)).expect("write to file");
+        }
         "fetch" => {
-            let session =
-                std::fs::read_to_string("session.txt").expect("session.txt with session key");
+            let parte = args.next().expect("Pass an argument man");
+            let force = args.flag("force");
+            let day: u32 = parte.parse().expect("Days are numbers man");
+            let path = runner::input_path(config.year, day);
+
+            if std::path::Path::new(&path).exists() && !force {
+                eprintln!("{path} is already here, pass --force to fetch it again");
+            } else {
+                let input = client::Client::from_config(&config)
+                    .and_then(|client| client.input(day))
+                    .unwrap_or_else(|error| {
+                        eprintln!("{error}");
+                        std::process::exit(1);
+                    });
+
+                std::fs::create_dir_all(format!("inputs/{}", config.year))
+                    .expect("make inputs dir");
+                std::fs::write(&path, input).expect("write to file");
+            }
+
+            // Nothing to set up if we've been here before
+            if !scaffold::exists(std::path::Path::new("."), config.year, day) {
+                if let Err(error) = scaffold::scaffold(std::path::Path::new("."), config.year, day)
+                {
+                    eprintln!("{error}");
+                }
+            }
+
+            // Examples might have been fixed up by hand, so they only get
+            // replaced on request, say once the page has grown a part two
+            if examples::saved(std::path::Path::new("."), config.year, day) && !force {
+                eprintln!("day{day} already has examples, pass --force to fetch them again");
+            } else {
+                let saved = examples::page(&config, day, None, true).and_then(|page| {
+                    examples::save(
+                        std::path::Path::new("."),
+                        config.year,
+                        day,
+                        &examples::extract(&page),
+                    )
+                });
+
+                match saved {
+                    Ok(answers) => {
+                        eprintln!("saved examples with {answers} answer(s) for day{day}")
+                    }
+                    Err(error) => eprintln!("no examples: {error}"),
+                }
+            }
+        }
+        "examples" => {
+            let day = args.next().expect("Pass an argument man");
+            let day = day.parse().expect("Days are numbers man");
+            let html = args.option("html");
+            let refresh = args.flag("refresh");
+
+            let saved = examples::page(&config, day, html.as_deref(), refresh).and_then(|page| {
+                examples::save(
+                    std::path::Path::new("."),
+                    config.year,
+                    day,
+                    &examples::extract(&page),
+                )
+            });
+
+            match saved {
+                Ok(answers) => eprintln!("saved examples with {answers} answer(s) for day{day}"),
+                Err(error) => {
+                    eprintln!("{error}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        "new" => {
+            let day = args.next().expect("Pass an argument man");
+            let day = day.parse().expect("Days are numbers man");
+
+            if let Err(error) = scaffold::scaffold(std::path::Path::new("."), config.year, day) {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        }
+        "repl" => {
+            let day = args.next().expect("Pass a day man");
+            let day = registry::find(config.year, &day).expect("No day that matches");
+            // Commands come in through stdin, so the input can't
+            assert!(
+                input_path.as_deref() != Some("-"),
+                "The repl needs stdin for itself man"
+            );
+            let input = runner::read_input(day, input_path.as_deref()).unwrap_or_else(|error| {
+                eprintln!("{error}");
+                std::process::exit(1);
+            });
+            let commands = std::io::stdin().lock();
+
+            if let Err(error) = repl::repl(day, &input, commands, &mut std::io::stdout()) {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        }
+        "gen" => {
+            let day = args.next().expect("Pass a day man");
+            let day = registry::find(config.year, &day).expect("No day that matches");
+            let size = args.option("size").expect("Pass a --size man");
+            let size = size.parse().expect("Sizes are numbers man");
+            // Says which seed it went with, so a good one can be had again
+            let seed = args.option("seed").map_or_else(
+                || {
+                    let now = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .expect("It's after 1970 man");
+                    let seed = now.as_nanos() as u64;
+
+                    eprintln!("seed {seed}");
+
+                    seed
+                },
+                |seed| seed.parse().expect("Seeds are numbers man"),
+            );
+
+            // Anything left over is for the day, like how many swaps day 24 wants
+            let rest: Vec<_> = std::iter::from_fn(|| args.next()).collect();
+            let rest: Vec<_> = rest.iter().map(String::as_str).collect();
+
+            match day.solver.generate(seed, size, &rest) {
+                Ok(input) => print!("{input}"),
+                Err(error) => {
+                    eprintln!("{}: {error}", day.name());
+                    std::process::exit(1);
+                }
+            }
+        }
+        "watch" => {
+            let day = args.next().expect("Pass a day man");
+            let day = registry::find(config.year, &day).expect("No day that matches");
+            let parts: Vec<_> = match args.next() {
+                Some(part) => vec![part.parse().expect("Parts are numbers man")],
+                None => day.parts().collect(),
+            };
+
+            watch::watch(day, &parts);
+        }
+        "submit" => {
+            let name = args.next().expect("Pass a day man");
+            let part = args.next().expect("Pass a part man");
+            let part = part.parse().expect("Parts are numbers man");
+            let day = name
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .expect("Days look like day5 man");
+
+            // Without an answer, we send whatever the solver comes up with
+            let answer = args.next().unwrap_or_else(|| {
+                let day = registry::find(config.year, &name).expect("No day that matches");
+                let input = runner::read_input(day, input_path.as_deref())
+                    .unwrap_or_else(|error| panic!("{error}"));
+
+                runner::run_or_exit(day, &input, part, config.timeout)
+                    .answer
+                    .to_string()
+            });
+
+            let verdict = client::Client::from_config(&config)
+                .and_then(|client| submit::submit(&client, &mut answers, day, part, &answer));
+
+            answers.save(&answers_path).unwrap_or_else(|error| panic!("{error}"));
+
+            match verdict {
+                Ok(verdict) => {
+                    println!("{answer}: {verdict}");
+
+                    if !matches!(
+                        verdict,
+                        submit::Verdict::Right | submit::Verdict::AlreadySolved
+                    ) {
+                        std::process::exit(1);
+                    }
+                }
+                Err(error) => {
+                    eprintln!("{error}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        day => {
+            let day = registry::find(config.year, day).expect("No day that matches");
+            let part = args.next().expect("Pass an argument man");
+            let part = part.parse().expect("Parts are numbers man");
+            let input = runner::read_input(day, input_path.as_deref())
+                .unwrap_or_else(|error| panic!("{error}"));
+
+            let run = match format {
+                runner::Format::Table => {
+                    let run = runner::run_or_exit(day, &input, part, config.timeout);
+
+                    println!("{answer}", answer = run.answer);
 
-            let input = ureq::get(&format!("https://adventofcode.com/2024/day/{parte}/input"))
-                .set("Cookie", &format!("session={key}", key = session.trim()))
-                .call()
-                .unwrap()
-                .into_string()
-                .unwrap();
+                    if let Some(memory) = run.memory {
+                        eprintln!("{memory}");
+                    }
 
-            std::fs::write(format!("inputs/day{parte}.txt"), input).expect("write to file");
+                    Ok(run)
+                }
+                runner::Format::Json => {
+                    let run = runner::run_part(day, &input, part, config.timeout);
 
-            std::fs::create_dir_all(format!("days/day{parte}/src")).unwrap();
+                    println!("{}", runner::json_record(day, part, &run));
 
-            let code = std::fs::read_to_string("days/starter/src/lib.rs")
-                .unwrap()
-                .replace("dayN", &format!("day{parte}"));
-            std::fs::write(format!("days/day{parte}/src/lib.rs"), code).unwrap();
+                    run
+                }
+            };
 
-            let manifest = std::fs::read_to_string("days/starter/Cargo.toml")
-                .unwrap()
-                .replace("dayN", &format!("day{parte}"));
-            std::fs::write(format!("days/day{parte}/Cargo.toml"), manifest).unwrap();
+            if let Ok(run) = &run {
+                if runner::record_own(&mut answers, day, part, &run.answer, input_path.as_deref()) {
+                    answers.save(&answers_path).unwrap_or_else(|error| panic!("{error}"));
+                }
+            } else {
+                std::process::exit(1);
+            }
         }
-        _ => panic!("No day that matches"),
     }
 }
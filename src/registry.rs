@@ -0,0 +1,13 @@
+use aoc::Day;
+
+// Generated by build.rs out of our `yYYYY_dayN` dependencies
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+pub fn find(year: u32, name: &str) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.year == year && day.name() == name)
+}
+
+/// Every day we've got for `year`, in order
+pub fn year(year: u32) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |day| day.year == year)
+}
@@ -0,0 +1,167 @@
+use std::{
+    io::{BufRead, Write},
+    panic::AssertUnwindSafe,
+    time::Instant,
+};
+
+use aoc::Day;
+
+use crate::runner::Input;
+
+/// Parses the day's input once and then runs commands against it, one per
+/// line of `commands`, until they run out or someone says quit
+pub fn repl(
+    day: &Day,
+    input: &Input,
+    commands: impl BufRead,
+    out: &mut impl Write,
+) -> Result<(), String> {
+    let name = day.name();
+    let mut lines = commands.lines();
+
+    let explored = day.solver.explore(&input.text, &mut |run| {
+        loop {
+            eprint!("{name}> ");
+
+            let Some(Ok(line)) = lines.next() else {
+                break;
+            };
+            let mut words = line.split_whitespace();
+            let Some(command) = words.next() else {
+                continue;
+            };
+            let args: Vec<_> = words.collect();
+
+            let said = match command {
+                "quit" | "exit" => break,
+                "help" => help(day),
+                _ => {
+                    let start = Instant::now();
+                    // A typo in an argument shouldn't cost us the session
+                    let result = std::panic::catch_unwind(AssertUnwindSafe(|| run(command, &args)));
+
+                    match result {
+                        Ok(Ok(said)) => format!("{said}\n({:.2?})", start.elapsed()),
+                        Ok(Err(error)) => format!("error: {error}"),
+                        Err(payload) => match payload.downcast::<aoc::Unsolvable>() {
+                            Ok(unsolvable) => format!("error: {}", unsolvable.0),
+                            Err(_) => "error: it panicked".to_string(),
+                        },
+                    }
+                }
+            };
+
+            writeln!(out, "{said}").expect("write to output");
+        }
+    });
+
+    explored.map_err(|error| error.diagnostic(&input.text, &input.path))
+}
+
+fn help(day: &Day) -> String {
+    let mut help = vec![
+        "partN [variant]: solves a part again".to_string(),
+        "help: this".to_string(),
+        "quit: bye".to_string(),
+    ];
+
+    for part in day.parts() {
+        let variants = day.solver.variants(part);
+
+        if variants.len() > 1 {
+            help.push(format!("part {part} variants: {}", variants.join(", ")));
+        }
+    }
+
+    help.extend(day.solver.commands().iter().map(|(_, help)| help.to_string()));
+
+    help.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::{command, Answer, Command, ParseError, Solution};
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        type Parsed<'a> = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+            aoc::parse::lines(input)
+                .map(|n| aoc::parse::number(input, n))
+                .collect()
+        }
+
+        fn part1(numbers: &Vec<u64>) -> Answer {
+            numbers.iter().map(|n| n * 2).sum::<u64>().into()
+        }
+
+        fn part2(numbers: &Vec<u64>) -> Answer {
+            numbers.iter().map(|n| n * 4).sum::<u64>().into()
+        }
+
+        fn commands() -> Vec<Command<Self>> {
+            vec![Command::new(
+                "nth",
+                "nth N: the Nth number",
+                |numbers, args| {
+                    let n: usize = command::arg(args, 0, "an index")?;
+
+                    Ok(numbers[n].to_string())
+                },
+            )]
+        }
+    }
+
+    static DOUBLER: Day = Day {
+        year: 0,
+        number: 0,
+        solver: &Doubler,
+    };
+
+    fn input(text: &str) -> Input {
+        Input {
+            path: "inputs/day0.txt".to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn runs_commands_until_quit() {
+        let commands = "part1\n\nnth 1\nnth x\nnth 9\nhelp\nquit\npart1\n";
+        let mut out = Vec::new();
+
+        repl(&DOUBLER, &input("1\n2\n3\n"), commands.as_bytes(), &mut out).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().filter(|line| !line.starts_with('(')).collect();
+
+        assert_eq!(
+            lines,
+            [
+                "12",
+                "2",
+                "error: \"x\" doesn't work as an index",
+                "error: it panicked",
+                "partN [variant]: solves a part again",
+                "help: this",
+                "quit: bye",
+                "nth N: the Nth number",
+            ]
+        );
+    }
+
+    #[test]
+    fn complains_about_the_input() {
+        let error = repl(
+            &DOUBLER,
+            &input("1\nx\n"),
+            "part1".as_bytes(),
+            &mut Vec::new(),
+        );
+
+        assert!(error.unwrap_err().contains("--> inputs/day0.txt:2:1"));
+    }
+}
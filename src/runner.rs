@@ -0,0 +1,657 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fmt::Display,
+    io::Read,
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    time::{Duration, Instant},
+};
+
+use aoc::{memory::Bytes, CancelToken, Cancelled, Day, Memory, ParseError, Run, Unsolvable};
+
+use crate::{
+    answers::{Answers, Check},
+    registry,
+};
+
+/// How results get printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// For people
+    Table,
+    /// One JSON object per line and part, for dashboards and such
+    Json,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("no {name} format, there's table and json")),
+        }
+    }
+}
+
+/// How `all` goes about its business
+pub struct Options {
+    /// Which year's calendar to run
+    pub year: u32,
+    pub fail_fast: bool,
+    /// How many parts get solved at once
+    pub jobs: usize,
+    pub format: Format,
+    pub timeout: Option<Duration>,
+    /// Whether the table gets columns for what each part allocated
+    pub memory: bool,
+}
+
+/// Why a part didn't come back with an answer
+#[derive(Debug)]
+pub enum Failure {
+    NoInput(String),
+    Parse {
+        path: String,
+        error: ParseError,
+    },
+    /// The input parsed, but the part can't make an answer out of it
+    Unsolvable(String),
+    Panic(String),
+    Timeout(Duration),
+}
+
+impl Failure {
+    /// What the JSON records call it
+    pub fn status(&self) -> &'static str {
+        match self {
+            Failure::Timeout(_) => "timeout",
+            _ => "error",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::NoInput(error) => f.write_str(error),
+            Failure::Parse { path, error } => write!(f, "{path}:{error}"),
+            Failure::Unsolvable(reason) => write!(f, "can't solve it: {reason}"),
+            Failure::Panic(message) => write!(f, "panicked: {message}"),
+            Failure::Timeout(limit) => write!(f, "gave up after {limit:.2?}"),
+        }
+    }
+}
+
+/// The JSON record for one part. Answers always go out as strings, since
+/// some of them are too big for whatever's reading them to take as numbers.
+pub fn json_record(day: &Day, part: u8, run: &Result<Run, Failure>) -> String {
+    let record = match run {
+        Ok(run) => serde_json::json!({
+            "year": day.year,
+            "day": day.number,
+            "part": part,
+            "answer": run.answer.to_string(),
+            "parse_ns": run.parse.as_nanos() as u64,
+            "solve_ns": run.solve.as_nanos() as u64,
+            "allocations": run.memory.map(|memory| memory.allocations),
+            "allocated_bytes": run.memory.map(|memory| memory.bytes),
+            "peak_bytes": run.memory.map(|memory| memory.peak),
+            "status": "ok",
+        }),
+        Err(failure) => serde_json::json!({
+            "year": day.year,
+            "day": day.number,
+            "part": part,
+            "answer": null,
+            "parse_ns": null,
+            "solve_ns": null,
+            "allocations": null,
+            "allocated_bytes": null,
+            "peak_bytes": null,
+            "status": failure.status(),
+            "error": failure.to_string(),
+        }),
+    };
+
+    record.to_string()
+}
+
+/// Puzzle input, along with where it came from for pointing at mistakes in it
+#[derive(Clone)]
+pub struct Input {
+    pub path: String,
+    pub text: String,
+}
+
+/// Where a day's input lives unless we're told otherwise
+pub fn input_path(year: u32, day: u32) -> String {
+    format!("inputs/{year}/day{day}.txt")
+}
+
+/// `-` reads stdin, a path reads a file, and by default we look for the
+/// day's file under `inputs/YYYY/`
+pub fn read_input(day: &Day, path: Option<&str>) -> Result<Input, String> {
+    match path {
+        Some("-") => {
+            let mut text = String::new();
+
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|e| format!("can't read stdin: {e}"))?;
+
+            Ok(Input {
+                path: "<stdin>".to_string(),
+                text,
+            })
+        }
+        Some(path) => Ok(Input {
+            path: path.to_string(),
+            text: std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?,
+        }),
+        None => {
+            let path = input_path(day.year, day.number);
+
+            Ok(Input {
+                text: std::fs::read_to_string(&path).map_err(|_| format!("no input at {path}"))?,
+                path,
+            })
+        }
+    }
+}
+
+/// Runs one part on a thread of its own, turning a panicking solver, input
+/// it can't parse or running past `timeout` into a failure so it can't take
+/// the rest of the calendar down with it. A part that runs out of time gets
+/// cancelled, but keeps going in the background if it never checks.
+pub fn run_part(
+    day: &'static Day,
+    input: &Input,
+    part: u8,
+    timeout: Option<Duration>,
+) -> Result<Run, Failure> {
+    let token = CancelToken::new();
+    let (sender, receiver) = mpsc::channel();
+    let text = input.text.clone();
+    let cancel = token.clone();
+
+    std::thread::Builder::new()
+        .name(format!("{} part {part}", day.name()))
+        // Same as the main thread gets, for the recursive ones
+        .stack_size(8 << 20)
+        .spawn(move || {
+            let run = cancel.scope(|| {
+                std::panic::catch_unwind(AssertUnwindSafe(|| day.solver.run(&text, part)))
+            });
+
+            // Nobody's listening if we took too long
+            let _ = sender.send(run);
+        })
+        .map_err(|e| Failure::Panic(format!("couldn't start a thread: {e}")))?;
+
+    let run = match timeout {
+        Some(limit) => receiver.recv_timeout(limit).map_err(|_| {
+            token.cancel();
+
+            Failure::Timeout(limit)
+        })?,
+        None => receiver.recv().expect("The part's thread hung up man"),
+    };
+
+    match run {
+        Ok(Ok(run)) => Ok(run),
+        Ok(Err(error)) => Err(Failure::Parse {
+            path: input.path.clone(),
+            error,
+        }),
+        Err(payload) if payload.is::<Cancelled>() => Err(Failure::Panic("cancelled".to_string())),
+        Err(payload) => match payload.downcast::<Unsolvable>() {
+            Ok(unsolvable) => Err(Failure::Unsolvable(unsolvable.0)),
+            Err(payload) => Err(Failure::Panic(panic_message(&*payload).to_string())),
+        },
+    }
+}
+
+/// Runs one part for the command line, where input that doesn't parse gets
+/// the full diagnostic and anything going wrong ends the show
+pub fn run_or_exit(day: &'static Day, input: &Input, part: u8, timeout: Option<Duration>) -> Run {
+    run_part(day, input, part, timeout).unwrap_or_else(|failure| {
+        match failure {
+            Failure::Parse { error, .. } => {
+                eprintln!("{}", error.diagnostic(&input.text, &input.path));
+            }
+            failure => eprintln!("{}", failure),
+        }
+
+        std::process::exit(1);
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "who knows"
+    }
+}
+
+/// Keeps a fresh answer if we had none on file, and complains if it doesn't
+/// match the one we had
+pub fn record(answers: &mut Answers, day: &Day, part: u8, answer: &aoc::Answer) {
+    match answers.check(&day.name(), part, answer) {
+        Check::Unknown => answers.record(&day.name(), part, answer),
+        Check::Right => {}
+        Check::Wrong { expected } => eprintln!(
+            "{name} part {part} answered {answer}, but {expected} is on file",
+            name = day.name()
+        ),
+    }
+}
+
+/// [`record`], but only when `input_path` didn't point us somewhere else,
+/// since examples, generated inputs and other people's inputs have answers
+/// of their own. Returns whether the answer got a look in.
+pub fn record_own(
+    answers: &mut Answers,
+    day: &Day,
+    part: u8,
+    answer: &aoc::Answer,
+    input_path: Option<&str>,
+) -> bool {
+    if input_path.is_some() {
+        return false;
+    }
+
+    record(answers, day, part, answer);
+
+    true
+}
+
+/// Does `work` on every item across `jobs` threads, handing the results to
+/// `done` in the items' order no matter who finishes first. Once `done`
+/// returns false, whatever hasn't started yet doesn't.
+fn in_order<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(&T, R) -> bool,
+) {
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            let sender = sender.clone();
+            let (next, stop, work) = (&next, &stop, &work);
+
+            scope.spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+
+                    if sender.send((index, work(item))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        drop(sender);
+
+        // Results that showed up before the ones in front of them
+        let mut waiting = BTreeMap::new();
+        let mut up_next = 0;
+
+        for (index, result) in receiver {
+            waiting.insert(index, result);
+
+            while let Some(result) = waiting.remove(&up_next) {
+                if !stop.load(Ordering::Relaxed) && !done(&items[up_next], result) {
+                    stop.store(true, Ordering::Relaxed);
+                }
+
+                up_next += 1;
+            }
+        }
+    });
+}
+
+/// Runs every registered day and part of the year, `jobs` at a time,
+/// printing a timing table (or JSON records) in calendar order as they
+/// finish. Returns whether everything came back with an answer.
+pub fn run_all(options: &Options, answers: &mut Answers) -> bool {
+    let Options {
+        year,
+        fail_fast,
+        jobs,
+        format,
+        timeout,
+        memory,
+    } = *options;
+    let mut total_parse = Duration::ZERO;
+    let mut total_solve = Duration::ZERO;
+    let mut failures = 0;
+    let started = Instant::now();
+
+    // Only there when asked for, and blank when there's nothing to show
+    let memory_columns = |columns: Option<[String; 3]>| match (memory, columns) {
+        (false, _) => String::new(),
+        (true, None) => format!(" {:>12} {:>10} {:>10}", "-", "-", "-"),
+        (true, Some([allocations, bytes, peak])) => {
+            format!(" {allocations:>12} {bytes:>10} {peak:>10}")
+        }
+    };
+    let memory_row = |memory: Option<Memory>| {
+        memory_columns(memory.map(|memory| {
+            [
+                memory.allocations.to_string(),
+                Bytes(memory.bytes).to_string(),
+                Bytes(memory.peak).to_string(),
+            ]
+        }))
+    };
+
+    if format == Format::Table {
+        println!(
+            "{:<6} {:>4} {:>12} {:>12}{}  answer",
+            "day",
+            "part",
+            "parse",
+            "solve",
+            memory_columns(Some(["allocations", "allocated", "peak"].map(String::from)))
+        );
+    }
+
+    let parts: Vec<(&'static Day, u8)> = registry::year(year)
+        .flat_map(|day| day.parts().map(move |part| (day, part)))
+        .collect();
+
+    // Every part reads its own input, so nobody has to wait on anybody else
+    let work = |&(day, part): &(&'static Day, u8)| {
+        read_input(day, None)
+            .map_err(Failure::NoInput)
+            .and_then(|input| run_part(day, &input, part, timeout))
+    };
+
+    in_order(&parts, jobs, work, |&(day, part), run| {
+        if format == Format::Json {
+            println!("{}", json_record(day, part, &run));
+        }
+
+        match run {
+            Ok(Run {
+                answer,
+                parse,
+                solve,
+                memory,
+            }) => {
+                total_parse += parse;
+                total_solve += solve;
+
+                record(answers, day, part, &answer);
+
+                if format == Format::Table {
+                    println!(
+                        "{:<6} {part:>4} {parse:>12.2?} {solve:>12.2?}{}  {answer}",
+                        day.name(),
+                        memory_row(memory)
+                    );
+                }
+
+                true
+            }
+            Err(failure) => {
+                failures += 1;
+
+                if format == Format::Table {
+                    println!(
+                        "{:<6} {part:>4} {:>12} {:>12}{}  {status}: {failure}",
+                        day.name(),
+                        "-",
+                        "-",
+                        memory_row(None),
+                        status = failure.status()
+                    );
+                }
+
+                !fail_fast
+            }
+        }
+    });
+
+    if format == Format::Table {
+        println!(
+            "{:<6} {:>4} {total_parse:>12.2?} {total_solve:>12.2?}{}  {:.2?} all together, {:.2?} on the clock",
+            "total",
+            "",
+            memory_row(None),
+            total_parse + total_solve,
+            started.elapsed()
+        );
+    }
+
+    if failures > 0 {
+        eprintln!("{failures} part(s) failed");
+    }
+
+    failures == 0
+}
+
+/// Reruns every part and holds it up against the answers on file. Returns
+/// whether nothing came back wrong or broken.
+pub fn verify(answers: &Answers, year: u32, timeout: Option<Duration>) -> bool {
+    let (mut right, mut wrong, mut failed, mut unknown) = (0, 0, 0, 0);
+
+    for day in registry::year(year) {
+        let input = read_input(day, None);
+
+        for part in day.parts() {
+            let run = input
+                .clone()
+                .map_err(Failure::NoInput)
+                .and_then(|input| run_part(day, &input, part, timeout));
+            let status = match run {
+                Ok(run) => match answers.check(&day.name(), part, &run.answer) {
+                    Check::Right => {
+                        right += 1;
+
+                        "ok".to_string()
+                    }
+                    Check::Wrong { expected } => {
+                        wrong += 1;
+
+                        format!(
+                            "MISMATCH: got {answer}, expected {expected}",
+                            answer = run.answer
+                        )
+                    }
+                    Check::Unknown => {
+                        unknown += 1;
+
+                        format!("nothing on file, got {answer}", answer = run.answer)
+                    }
+                },
+                Err(failure) => {
+                    failed += 1;
+
+                    format!("{status}: {failure}", status = failure.status())
+                }
+            };
+
+            println!("{:<6} {part:>4}  {status}", day.name());
+        }
+    }
+
+    println!("{right} right, {wrong} wrong, {failed} failed, {unknown} unknown");
+
+    wrong == 0 && failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_things_in_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let mut seen = Vec::new();
+
+        // Earlier items take longer, so they'd finish last if we let them
+        in_order(
+            &items,
+            4,
+            |&n| {
+                std::thread::sleep(Duration::from_millis(20 - n));
+                n * 2
+            },
+            |&n, doubled| {
+                seen.push((n, doubled));
+                n < 9
+            },
+        );
+
+        assert_eq!(seen, (0..10).map(|n| (n, n * 2)).collect::<Vec<_>>());
+    }
+
+    /// Never finishes unless someone tells it to
+    struct Forever;
+
+    static STOPPED: AtomicBool = AtomicBool::new(false);
+
+    impl aoc::Solution for Forever {
+        type Parsed<'a> = ();
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_parsed: &()) -> aoc::Answer {
+            while !aoc::cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+
+            STOPPED.store(true, Ordering::Relaxed);
+
+            aoc::check_cancelled();
+            unreachable!()
+        }
+
+        fn part2(parsed: &()) -> aoc::Answer {
+            Self::part1(parsed)
+        }
+    }
+
+    #[test]
+    fn gives_up_on_slow_parts() {
+        static DAY: Day = Day {
+            year: 0,
+            number: 0,
+            solver: &Forever,
+        };
+        let input = Input {
+            path: "nowhere".to_string(),
+            text: String::new(),
+        };
+
+        let run = run_part(&DAY, &input, 1, Some(Duration::from_millis(20)));
+
+        assert!(matches!(run, Err(Failure::Timeout(_))));
+
+        // It got the memo and stopped
+        let started = Instant::now();
+
+        while !STOPPED.load(Ordering::Relaxed) {
+            assert!(started.elapsed() < Duration::from_secs(5));
+            std::thread::yield_now();
+        }
+    }
+
+    /// Can't make head nor tail of anything
+    struct Stumped;
+
+    impl aoc::Solution for Stumped {
+        type Parsed<'a> = ();
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_parsed: &()) -> aoc::Answer {
+            aoc::unsolvable("that's not a puzzle")
+        }
+
+        fn part2(parsed: &()) -> aoc::Answer {
+            Self::part1(parsed)
+        }
+    }
+
+    #[test]
+    fn says_why_parts_give_up() {
+        static DAY: Day = Day {
+            year: 0,
+            number: 0,
+            solver: &Stumped,
+        };
+        let input = Input {
+            path: "nowhere".to_string(),
+            text: String::new(),
+        };
+
+        let failure = run_part(&DAY, &input, 1, None).err().unwrap();
+
+        assert!(matches!(failure, Failure::Unsolvable(_)));
+        assert_eq!(failure.to_string(), "can't solve it: that's not a puzzle");
+    }
+
+    #[test]
+    fn only_keeps_answers_to_our_own_input() {
+        let day = registry::find(2024, "day1").unwrap();
+        let mut answers = Answers::default();
+        let answer = aoc::Answer::Int(11);
+
+        assert!(!record_own(
+            &mut answers,
+            day,
+            1,
+            &answer,
+            Some("example.txt")
+        ));
+        assert!(!record_own(&mut answers, day, 1, &answer, Some("-")));
+        assert_eq!(answers.to_string(), Answers::default().to_string());
+
+        assert!(record_own(&mut answers, day, 1, &answer, None));
+        assert_eq!(answers.get("day1", 1), Some("11".to_string()));
+    }
+
+    #[test]
+    fn writes_json_records() {
+        let day = registry::find(2024, "day5").unwrap();
+        let run = Run {
+            answer: aoc::Answer::Int(143),
+            parse: Duration::from_micros(2),
+            solve: Duration::from_nanos(350),
+            memory: Some(Memory {
+                allocations: 3,
+                bytes: 300,
+                peak: 200,
+            }),
+        };
+
+        assert_eq!(
+            json_record(day, 1, &Ok(run)),
+            r#"{"allocated_bytes":300,"allocations":3,"answer":"143","day":5,"parse_ns":2000,"part":1,"peak_bytes":200,"solve_ns":350,"status":"ok","year":2024}"#
+        );
+        assert_eq!(
+            json_record(day, 2, &Err(Failure::Panic("oops".to_string()))),
+            r#"{"allocated_bytes":null,"allocations":null,"answer":null,"day":5,"error":"panicked: oops","parse_ns":null,"part":2,"peak_bytes":null,"solve_ns":null,"status":"error","year":2024}"#
+        );
+    }
+}
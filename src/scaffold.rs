@@ -0,0 +1,164 @@
+use std::path::Path;
+
+/// Where a day's crate lives
+pub fn dir(year: u32, day: u32) -> String {
+    format!("days/{year}/day{day}")
+}
+
+/// What a day's crate is called, which has to include the year for every
+/// year's day 1 to get along in one workspace
+pub fn package(year: u32, day: u32) -> String {
+    format!("y{year}_day{day}")
+}
+
+/// Whether the day has code already, so there's nothing to set up
+pub fn exists(root: &Path, year: u32, day: u32) -> bool {
+    root.join(dir(year, day)).join("src/lib.rs").exists()
+}
+
+/// Sets up `days/YYYY/dayN` out of `days/starter` and wires it into the
+/// workspace and the runner's dependencies, which is all the registry needs
+/// to find it. Refuses to touch a day that already has code in it.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> Result<(), String> {
+    let dir = root.join(self::dir(year, day));
+    let lib = dir.join("src/lib.rs");
+
+    if exists(root, year, day) {
+        return Err(format!("{} already exists, not touching it", lib.display()));
+    }
+
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|e| format!("can't read {}: {e}", path.display()))
+    };
+    let write = |path: &Path, contents: &str| {
+        std::fs::write(path, contents).map_err(|e| format!("can't write {}: {e}", path.display()))
+    };
+
+    let starter = root.join("days/starter");
+    let code = read(&starter.join("src/lib.rs"))?
+        .replace("dayN", &format!("day{day}"))
+        .replace("DayN", &format!("Day{day}"));
+    let manifest = read(&starter.join("Cargo.toml"))?.replace("dayN", &package(year, day));
+
+    std::fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("can't create {}: {e}", dir.display()))?;
+    write(&lib, &code)?;
+    write(&dir.join("Cargo.toml"), &manifest)?;
+
+    let root_manifest = root.join("Cargo.toml");
+    let wired = add_dependency(&add_member(&read(&root_manifest)?, year, day)?, year, day)?;
+
+    write(&root_manifest, &wired)
+}
+
+/// Adds `days/YYYY/dayN` to the end of the workspace members, if it's not there yet
+fn add_member(manifest: &str, year: u32, day: u32) -> Result<String, String> {
+    let member = format!("\"{}\"", dir(year, day));
+
+    if manifest.contains(&member) {
+        return Ok(manifest.to_string());
+    }
+
+    let start = manifest
+        .find("members = [")
+        .ok_or("no workspace members in Cargo.toml")?;
+    let end = start + manifest[start..].find(']').ok_or("workspace members never end")?;
+
+    Ok(format!(
+        "{}    {member},\n{}",
+        &manifest[..end],
+        &manifest[end..]
+    ))
+}
+
+/// Adds `yYYYY_dayN` to the end of `[dependencies]`, if it's not there yet
+fn add_dependency(manifest: &str, year: u32, day: u32) -> Result<String, String> {
+    let package = package(year, day);
+    let dependency = format!("{package} = {{ path = \"./{}\" }}", dir(year, day));
+
+    if manifest.lines().any(|line| line.starts_with(&format!("{package} ="))) {
+        return Ok(manifest.to_string());
+    }
+
+    let start = manifest
+        .find("[dependencies]")
+        .ok_or("no [dependencies] in Cargo.toml")?;
+    let end = manifest[start..]
+        .find("\n[")
+        .map_or(manifest.len(), |end| start + end);
+    let section = manifest[..end].trim_end();
+
+    Ok(format!("{section}\n{dependency}\n{}", &manifest[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[package]
+name = "aoc2024"
+
+[workspace]
+members = [
+    "libs/aoc",
+    "days/2024/day1",
+]
+
+[dependencies]
+aoc = { path = "./libs/aoc" }
+y2024_day1 = { path = "./days/2024/day1" }
+
+[dev-dependencies]
+tiny_http = "0.12"
+"#;
+
+    #[test]
+    fn wires_up_the_manifest() {
+        let wired = add_dependency(&add_member(MANIFEST, 2024, 2).unwrap(), 2024, 2).unwrap();
+
+        assert!(wired.contains("    \"days/2024/day1\",\n    \"days/2024/day2\",\n]"));
+        assert!(wired.contains(
+            "y2024_day1 = { path = \"./days/2024/day1\" }\ny2024_day2 = { path = \"./days/2024/day2\" }\n\n[dev-dependencies]"
+        ));
+
+        // Doing it twice changes nothing
+        assert_eq!(
+            add_dependency(&add_member(&wired, 2024, 2).unwrap(), 2024, 2).unwrap(),
+            wired
+        );
+
+        // Another year's day 2 is a different day
+        let older = add_dependency(&add_member(&wired, 2023, 2).unwrap(), 2023, 2).unwrap();
+
+        assert!(older.contains("    \"days/2023/day2\",\n]"));
+        assert!(older.contains("y2023_day2 = { path = \"./days/2023/day2\" }"));
+    }
+
+    #[test]
+    fn scaffolds_once() {
+        let root = std::env::temp_dir().join(format!("aoc2024-scaffold-{}", std::process::id()));
+        let starter = root.join("days/starter");
+
+        std::fs::create_dir_all(starter.join("src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::write(starter.join("Cargo.toml"), "name = \"dayN\"\n").unwrap();
+        std::fs::write(starter.join("src/lib.rs"), "pub struct DayN;\n").unwrap();
+
+        assert!(!exists(&root, 2024, 7));
+        scaffold(&root, 2024, 7).unwrap();
+        assert!(exists(&root, 2024, 7));
+
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+
+        assert_eq!(read("days/2024/day7/src/lib.rs"), "pub struct Day7;\n");
+        assert_eq!(read("days/2024/day7/Cargo.toml"), "name = \"y2024_day7\"\n");
+        assert!(read("Cargo.toml").contains("y2024_day7 = { path = \"./days/2024/day7\" }"));
+
+        std::fs::write(root.join("days/2024/day7/src/lib.rs"), "// hard work").unwrap();
+
+        assert!(scaffold(&root, 2024, 7).is_err());
+        assert_eq!(read("days/2024/day7/src/lib.rs"), "// hard work");
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
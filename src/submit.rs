@@ -0,0 +1,206 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{answers::Answers, client::Client, examples};
+
+/// What the site made of an answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    AlreadySolved,
+    RateLimited {
+        wait: Duration,
+    },
+    /// The site said something we don't know how to read, so here it is
+    Confusing(String),
+}
+
+impl Verdict {
+    /// Reads the `<article>` the site puts its reply in
+    pub fn from_html(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Verdict::Right
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited {
+                wait: wait_time(&text).unwrap_or_default(),
+            }
+        } else {
+            Verdict::Confusing(text)
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Right => f.write_str("right answer, have a star"),
+            Verdict::Wrong => f.write_str("wrong answer"),
+            Verdict::TooHigh => f.write_str("wrong answer, too high"),
+            Verdict::TooLow => f.write_str("wrong answer, too low"),
+            Verdict::AlreadySolved => f.write_str("that part is already solved"),
+            Verdict::RateLimited { wait } => write!(f, "too soon, wait {wait:?} and try again"),
+            Verdict::Confusing(text) => write!(f, "the site said something weird: {text}"),
+        }
+    }
+}
+
+/// Everything between `<article>` tags with the markup stripped out
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    examples::text(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Pulls the wait out of "You have 4m 36s left to wait"
+fn wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|chunk| {
+            let split = chunk.find(|c: char| !c.is_ascii_digit())?;
+            let (n, unit) = chunk.split_at(split);
+            let n: u64 = n.parse().ok()?;
+
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Submits `answer` unless the site already told us it's wrong, and keeps
+/// track of what it says about it
+pub fn submit(
+    client: &Client,
+    answers: &mut Answers,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let name = format!("day{day}");
+
+    if answers.is_known_wrong(&name, part, answer) {
+        return Err(format!(
+            "{answer} was already wrong for {name} part {part}, not sending it again"
+        ));
+    }
+
+    let verdict = Verdict::from_html(&client.submit(day, part, answer)?);
+
+    if verdict == Verdict::Right {
+        answers.record(&name, part, &answer_value(answer));
+    } else if verdict.is_wrong() {
+        answers.record_wrong(&name, part, answer);
+    }
+
+    Ok(verdict)
+}
+
+/// Numbers go back in as numbers, so they read the same as what the runner records
+fn answer_value(answer: &str) -> aoc::Answer {
+    answer.parse::<i64>().map_or_else(|_| answer.into(), aoc::Answer::Int)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::serve;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        let right = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.");
+        let low = page("That's not the right answer; your answer is too low.");
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        let solved = page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/5\">[Return to Day 5]</a>");
+        let limited = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 36s left to wait.");
+        let short = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 36s left to wait.");
+
+        assert_eq!(Verdict::from_html(&right), Verdict::Right);
+        assert_eq!(Verdict::from_html(&high), Verdict::TooHigh);
+        assert_eq!(Verdict::from_html(&low), Verdict::TooLow);
+        assert_eq!(Verdict::from_html(&wrong), Verdict::Wrong);
+        assert_eq!(Verdict::from_html(&solved), Verdict::AlreadySolved);
+        assert_eq!(
+            Verdict::from_html(&limited),
+            Verdict::RateLimited {
+                wait: Duration::from_secs(276)
+            }
+        );
+        assert_eq!(
+            Verdict::from_html(&short),
+            Verdict::RateLimited {
+                wait: Duration::from_secs(36)
+            }
+        );
+        assert_eq!(
+            Verdict::from_html(&page("Huh <em>what</em>")),
+            Verdict::Confusing("Huh what".to_string())
+        );
+    }
+
+    #[test]
+    fn records_and_refuses() {
+        let low = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let right = "<article><p>That's the right answer!</p></article>";
+        let (base_url, server) = serve(vec![(200, low), (200, right)]);
+        let client = Client::new(&base_url, 2024, "cookie".to_string());
+        let mut answers = Answers::default();
+
+        assert_eq!(
+            submit(&client, &mut answers, 5, 2, "12"),
+            Ok(Verdict::TooLow)
+        );
+        // Never makes it to the server
+        assert!(submit(&client, &mut answers, 5, 2, "12").is_err());
+        assert_eq!(
+            submit(&client, &mut answers, 5, 2, "6204"),
+            Ok(Verdict::Right)
+        );
+
+        assert_eq!(answers.get("day5", 2).as_deref(), Some("6204"));
+        assert!(answers.is_known_wrong("day5", 2, "12"));
+
+        let seen = server.join().unwrap();
+
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0].method, "POST");
+        assert_eq!(seen[0].url, "/2024/day/5/answer");
+        assert_eq!(seen[0].body, "level=2&answer=12");
+        assert_eq!(seen[1].cookie.as_deref(), Some("session=cookie"));
+    }
+}
@@ -0,0 +1,255 @@
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::{Duration, SystemTime},
+};
+
+use aoc::Day;
+
+use crate::{runner, scaffold};
+
+/// How often the fallback looks at the files again
+const POLL_EVERY: Duration = Duration::from_millis(500);
+/// Editors like to save in a few steps, so changes this close together count as one
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// Rebuilds and reruns `parts` of a day, along with its tests, every time its
+/// code or input changes. Runs until it gets killed.
+pub fn watch(day: &Day, parts: &[u8]) {
+    let src = PathBuf::from(scaffold::dir(day.year, day.number)).join("src");
+    let input = PathBuf::from(runner::input_path(day.year, day.number));
+    let mut changes = Changes::new(&src, &input);
+    let mut previous = vec![None; parts.len()];
+
+    loop {
+        // Clear the screen and go back to the top
+        print!("\x1b[2J\x1b[H");
+
+        rerun(day, parts, &mut previous);
+
+        eprintln!("\nwatching {} and {}...", src.display(), input.display());
+
+        changes.wait();
+    }
+}
+
+fn rerun(day: &Day, parts: &[u8], previous: &mut [Option<String>]) {
+    let (year, name) = (day.year.to_string(), day.name());
+
+    let built = Command::new("cargo").args(["build", "--release", "--quiet"]).status();
+
+    if !built.is_ok_and(|status| status.success()) {
+        println!("build failed");
+        return;
+    }
+
+    for (part, previous) in parts.iter().zip(previous.iter_mut()) {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "--release",
+                "--quiet",
+                "--",
+                &year,
+                &name,
+                &part.to_string(),
+            ])
+            .stderr(Stdio::inherit())
+            .output();
+
+        let answer = match output {
+            Ok(output) if output.status.success() => {
+                String::from_utf8_lossy(&output.stdout).trim().to_string()
+            }
+            _ => {
+                println!("part {part} failed");
+                continue;
+            }
+        };
+
+        println!("part {part}: {}", compare(previous.as_deref(), &answer));
+
+        *previous = Some(answer);
+    }
+
+    let tested = Command::new("cargo")
+        .args(["test", "--release", "--quiet", "-p"])
+        .arg(scaffold::package(day.year, day.number))
+        .status();
+
+    if !tested.is_ok_and(|status| status.success()) {
+        println!("tests failed");
+    }
+}
+
+/// The answer, and how it holds up against the last one
+fn compare(previous: Option<&str>, answer: &str) -> String {
+    match previous {
+        None => answer.to_string(),
+        Some(previous) if previous == answer => format!("{answer} (same as before)"),
+        Some(previous) => format!("{answer} (was {previous})"),
+    }
+}
+
+/// Something that blocks until the files we care about change
+enum Changes {
+    #[cfg(target_os = "linux")]
+    Notify {
+        _watcher: notify::RecommendedWatcher,
+        events: mpsc::Receiver<()>,
+    },
+    Poll {
+        paths: Vec<PathBuf>,
+        seen: Vec<(PathBuf, SystemTime, u64)>,
+    },
+}
+
+impl Changes {
+    /// Filesystem notifications where we have them, polling where we don't
+    /// or they don't work out
+    fn new(src: &Path, input: &Path) -> Self {
+        #[cfg(target_os = "linux")]
+        match Self::notify(src, input) {
+            Ok(changes) => return changes,
+            Err(error) => eprintln!("no notifications ({error}), polling instead"),
+        }
+
+        Self::poll(vec![src.to_path_buf(), input.to_path_buf()])
+    }
+
+    #[cfg(target_os = "linux")]
+    fn notify(src: &Path, input: &Path) -> notify::Result<Self> {
+        use notify::{EventKind, RecursiveMode, Watcher};
+
+        let src = src.canonicalize()?;
+        // Editors tend to replace files instead of writing them, so we
+        // watch the folder the input lives in rather than the input itself
+        let inputs = input.parent().unwrap_or(Path::new(".")).canonicalize()?;
+        let input = inputs.join(input.file_name().unwrap_or_default());
+        let (sender, events) = mpsc::channel();
+
+        let (watching_src, watching_input) = (src.clone(), input.clone());
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+
+                // Building reads everything, which shouldn't set off another build
+                if matches!(event.kind, EventKind::Access(_)) {
+                    return;
+                }
+
+                let relevant = event
+                    .paths
+                    .iter()
+                    .any(|path| path.starts_with(&watching_src) || *path == watching_input);
+
+                if relevant {
+                    let _ = sender.send(());
+                }
+            })?;
+
+        watcher.watch(&src, RecursiveMode::Recursive)?;
+        watcher.watch(&inputs, RecursiveMode::NonRecursive)?;
+
+        Ok(Changes::Notify {
+            _watcher: watcher,
+            events,
+        })
+    }
+
+    fn poll(paths: Vec<PathBuf>) -> Self {
+        let seen = snapshot(&paths);
+
+        Changes::Poll { paths, seen }
+    }
+
+    fn wait(&mut self) {
+        match self {
+            #[cfg(target_os = "linux")]
+            Changes::Notify { events, .. } => {
+                if events.recv().is_err() {
+                    // The watcher's gone, so there's nothing left to wait for
+                    std::process::exit(1);
+                }
+
+                while events.recv_timeout(SETTLE).is_ok() {}
+            }
+            Changes::Poll { paths, seen } => loop {
+                std::thread::sleep(POLL_EVERY);
+
+                let now = snapshot(paths);
+
+                if now != *seen {
+                    *seen = now;
+                    break;
+                }
+            },
+        }
+    }
+}
+
+/// Every file under `paths`, with when it was last touched and how big it is
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, SystemTime, u64)> {
+    fn visit(path: &Path, files: &mut Vec<(PathBuf, SystemTime, u64)>) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+
+        if metadata.is_dir() {
+            for entry in std::fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), files);
+            }
+        } else {
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+
+            files.push((path.to_path_buf(), modified, metadata.len()));
+        }
+    }
+
+    let mut files = Vec::new();
+
+    for path in paths {
+        visit(path, &mut files);
+    }
+
+    files.sort();
+
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_answers() {
+        assert_eq!(compare(None, "42"), "42");
+        assert_eq!(compare(Some("42"), "42"), "42 (same as before)");
+        assert_eq!(compare(Some("41"), "42"), "42 (was 41)");
+    }
+
+    #[test]
+    fn notices_changes_when_polling() {
+        let root = std::env::temp_dir().join(format!("aoc2024-watch-{}", std::process::id()));
+        let src = root.join("src");
+        let input = root.join("input.txt");
+
+        std::fs::create_dir_all(&src).unwrap();
+        std::fs::write(src.join("lib.rs"), "fn main() {}").unwrap();
+
+        let mut changes = Changes::poll(vec![src.clone(), input.clone()]);
+
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(POLL_EVERY / 2);
+            std::fs::write(input, "1 2 3").unwrap();
+        });
+
+        changes.wait();
+        writer.join().unwrap();
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}